@@ -0,0 +1,393 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, ValueEnum};
+use picomatch_rs::{
+    compile_matcher, make_re, parse, scan, CompileOptions, Engine, IgnoreTree, Matcher, ScanOptions,
+};
+use serde_json::{json, Value};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Filter paths by glob patterns and inspect how globs compile.
+///
+/// Paths are read one per line from stdin, from `--from FILE` or by walking
+/// `--walk DIR`, and the ones selected by PATTERNS are printed. A path is
+/// selected when it matches any include pattern (or there are none) and no
+/// `!` exclude pattern. The exit status is 0 if a path was printed, 1 if none
+/// was and 2 on error.
+#[derive(Debug, Parser)]
+#[command(name = "picomatch", version)]
+struct Cli {
+    /// Glob patterns; a leading `!` turns a pattern into an exclude
+    #[arg(required = true)]
+    patterns: Vec<String>,
+
+    /// Read paths from FILE instead of stdin
+    #[arg(short, long, value_name = "FILE", conflicts_with = "walk")]
+    from: Option<PathBuf>,
+
+    /// Walk DIR and filter its files, skipping paths ignored by .gitignore
+    #[arg(short, long, value_name = "DIR")]
+    walk: Option<PathBuf>,
+
+    /// With --walk, do not read .gitignore or .ignore files
+    #[arg(long, requires = "walk")]
+    no_ignore: bool,
+
+    /// Separate input and output paths with NUL instead of newline
+    #[arg(short = '0', long)]
+    null: bool,
+
+    /// Print the paths that are not selected instead
+    #[arg(short = 'v', long)]
+    invert: bool,
+
+    /// Print the `scan` result of each pattern as JSON and exit
+    #[arg(long, group = "debug", help_heading = "Debugging")]
+    scan: bool,
+
+    /// Print the `parse` result of each pattern as JSON and exit
+    #[arg(long, group = "debug", help_heading = "Debugging")]
+    parse: bool,
+
+    /// Print the regex `make_re` builds for each pattern as JSON and exit
+    #[arg(long, group = "debug", help_heading = "Debugging")]
+    make_re: bool,
+
+    /// Report as JSON how each pattern treats PATH and why; may be repeated
+    #[arg(
+        long = "test",
+        value_name = "PATH",
+        group = "debug",
+        help_heading = "Debugging"
+    )]
+    test: Vec<String>,
+
+    #[command(flatten)]
+    options: GlobOptions,
+}
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Glob options")]
+struct GlobOptions {
+    /// Let `*` match across slashes, like bash without globstar
+    #[arg(long)]
+    bash: bool,
+    /// Match patterns without slashes against the basename (alias of --match-base)
+    #[arg(long)]
+    basename: bool,
+    /// Match anywhere in the path instead of the whole path
+    #[arg(long)]
+    contains: bool,
+    /// Let wildcards match dotfiles
+    #[arg(long)]
+    dot: bool,
+    /// Regex flags to compile with, for example `i`
+    #[arg(long, value_name = "FLAGS")]
+    flags: Option<String>,
+    /// Match patterns without slashes against the basename
+    #[arg(long)]
+    match_base: bool,
+    /// Treat braces literally
+    #[arg(long)]
+    nobrace: bool,
+    /// Treat brackets literally
+    #[arg(long)]
+    nobracket: bool,
+    /// Treat extglobs literally
+    #[arg(long, visible_alias = "noext")]
+    noextglob: bool,
+    /// Treat `**` like `*`
+    #[arg(long)]
+    noglobstar: bool,
+    /// Match case-insensitively
+    #[arg(long)]
+    nocase: bool,
+    /// Treat a leading `!` literally
+    #[arg(long)]
+    nonegate: bool,
+    /// Disable POSIX character classes such as `[[:alpha:]]`
+    #[arg(long)]
+    no_posix: bool,
+    /// Reject unbalanced brackets and parentheses
+    #[arg(long)]
+    strict_brackets: bool,
+    /// Do not let patterns match a trailing slash implicitly
+    #[arg(long)]
+    strict_slashes: bool,
+    /// Remove backslashes that escape ordinary characters
+    #[arg(long)]
+    unescape: bool,
+    /// Accept backslashes as path separators
+    #[arg(long)]
+    windows: bool,
+    /// Use regex rules for `+` and for quantifiers after groups and brackets
+    #[arg(long)]
+    regex: bool,
+    /// Reject regex syntax that picomatch would pass through
+    #[arg(long)]
+    strict_glob: bool,
+    /// Matching engine to use
+    #[arg(long, value_enum, value_name = "ENGINE")]
+    engine: Option<EngineArg>,
+    /// Keep quotes around quoted pattern segments
+    #[arg(long)]
+    keep_quotes: bool,
+    /// Maximum pattern length in bytes
+    #[arg(long, value_name = "N")]
+    max_length: Option<usize>,
+    /// Maximum regex backtracking steps per match
+    #[arg(long, value_name = "N")]
+    backtrack_limit: Option<usize>,
+    /// Maximum nesting of braces and extglobs
+    #[arg(long, value_name = "N")]
+    max_nesting_depth: Option<usize>,
+    /// Maximum number of alternatives in a pattern
+    #[arg(long, value_name = "N")]
+    max_alternations: Option<usize>,
+    /// Maximum size of the compiled regex source in bytes
+    #[arg(long, value_name = "N")]
+    max_regex_size: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum EngineArg {
+    Auto,
+    Regex,
+    Glob,
+}
+
+impl GlobOptions {
+    fn compile_options(&self) -> CompileOptions {
+        CompileOptions {
+            bash: self.bash,
+            basename: self.basename,
+            contains: self.contains,
+            dot: self.dot,
+            flags: self.flags.clone().unwrap_or_default(),
+            match_base: self.match_base,
+            nobrace: self.nobrace,
+            nobracket: self.nobracket,
+            noextglob: self.noextglob,
+            noglobstar: self.noglobstar,
+            nocase: self.nocase,
+            nonegate: self.nonegate,
+            posix: !self.no_posix,
+            strict_brackets: self.strict_brackets,
+            strict_slashes: self.strict_slashes,
+            unescape: self.unescape,
+            windows: self.windows,
+            regex: self.regex,
+            strict_glob: self.strict_glob,
+            engine: match self.engine {
+                None | Some(EngineArg::Auto) => Engine::Auto,
+                Some(EngineArg::Regex) => Engine::Regex,
+                Some(EngineArg::Glob) => Engine::Glob,
+            },
+            keep_quotes: self.keep_quotes,
+            max_length: self.max_length,
+            backtrack_limit: self.backtrack_limit,
+            max_nesting_depth: self.max_nesting_depth,
+            max_alternations: self.max_alternations,
+            max_regex_size: self.max_regex_size,
+            ..CompileOptions::default()
+        }
+    }
+
+    fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            parts: true,
+            tokens: true,
+            noext: self.noextglob,
+            nonegate: self.nonegate,
+            unescape: self.unescape,
+            ..ScanOptions::default()
+        }
+    }
+}
+
+struct Pattern {
+    glob: String,
+    negated: bool,
+    matcher: Matcher,
+}
+
+// Negated patterns compile to matchers that accept everything the rest of the
+// pattern does not, so an exclude rejects a path when its matcher fails.
+fn compile_patterns(patterns: &[String], options: &CompileOptions) -> Result<Vec<Pattern>> {
+    patterns
+        .iter()
+        .map(|glob| {
+            // `compile_matcher` leaves `max_length` to the bindings.
+            if let Some(limit) = options.max_length.filter(|&limit| glob.len() > limit) {
+                return Err(format!(
+                    "invalid pattern `{glob}`: length {} exceeds maximum allowed length {limit}",
+                    glob.len()
+                )
+                .into());
+            }
+            let matcher = compile_matcher(glob, options)
+                .map_err(|err| format!("invalid pattern `{glob}`: {err}"))?;
+            let negated = parse(glob, options).is_some_and(|state| state.negated);
+            Ok(Pattern {
+                glob: glob.clone(),
+                negated,
+                matcher,
+            })
+        })
+        .collect()
+}
+
+// Paths are matched as raw bytes, so names that are not valid UTF-8 are
+// neither skipped nor altered.
+fn is_selected(patterns: &[Pattern], path: &[u8]) -> Result<bool> {
+    let mut has_include = false;
+    let mut included = false;
+
+    for pattern in patterns {
+        let matched = pattern.matcher.is_match_bytes(path)?;
+        if pattern.negated {
+            if !matched {
+                return Ok(false);
+            }
+        } else {
+            has_include = true;
+            included |= matched;
+        }
+    }
+
+    Ok(included || !has_include)
+}
+
+fn print_json(out: &mut impl Write, value: &Value) -> Result<()> {
+    serde_json::to_writer(&mut *out, value)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn debug(cli: &Cli, options: &CompileOptions, out: &mut impl Write) -> Result<()> {
+    if !cli.test.is_empty() {
+        let patterns = compile_patterns(&cli.patterns, options)?;
+        for path in &cli.test {
+            let mut reports = Vec::with_capacity(patterns.len());
+            for pattern in &patterns {
+                let explanation = pattern.matcher.explain(path)?;
+                reports.push(json!({
+                    "pattern": pattern.glob,
+                    "negated": pattern.negated,
+                    "isMatch": explanation.is_match,
+                    "regex": make_re(&pattern.glob, options, false).map(|re| re.source),
+                    "explanation": explanation
+                        .steps
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                }));
+            }
+            print_json(
+                out,
+                &json!({
+                    "path": path,
+                    "selected": is_selected(&patterns, path.as_bytes())?,
+                    "patterns": reports,
+                }),
+            )?;
+        }
+        return Ok(());
+    }
+
+    for glob in &cli.patterns {
+        let value = if cli.scan {
+            serde_json::to_value(scan(glob, &cli.options.scan_options()))?
+        } else if cli.parse {
+            serde_json::to_value(parse(glob, options))?
+        } else {
+            serde_json::to_value(make_re(glob, options, false))?
+        };
+        print_json(out, &value)?;
+    }
+
+    Ok(())
+}
+
+fn filter(cli: &Cli, options: &CompileOptions, out: &mut impl Write) -> Result<bool> {
+    let patterns = compile_patterns(&cli.patterns, options)?;
+    let separator = if cli.null { b'\0' } else { b'\n' };
+    let mut printed = false;
+
+    let mut emit = |path: &[u8]| -> Result<()> {
+        if is_selected(&patterns, path)? != cli.invert {
+            out.write_all(path)?;
+            out.write_all(&[separator])?;
+            printed = true;
+        }
+        Ok(())
+    };
+
+    if let Some(dir) = &cli.walk {
+        let mut tree = if cli.no_ignore {
+            IgnoreTree::with_file_names(dir, Vec::<String>::new())
+        } else {
+            IgnoreTree::new(dir)
+        };
+        for path in tree.walk()? {
+            emit(path.as_os_str().as_encoded_bytes())?;
+        }
+        return Ok(printed);
+    }
+
+    let input: Box<dyn BufRead> = match &cli.from {
+        Some(path) => Box::new(BufReader::new(
+            File::open(path).map_err(|err| format!("{}: {err}", path.display()))?,
+        )),
+        None => Box::new(io::stdin().lock()),
+    };
+    for line in input.split(separator) {
+        let line = line?;
+        let path = line.strip_suffix(b"\r").unwrap_or(&line);
+        if !path.is_empty() {
+            emit(path)?;
+        }
+    }
+
+    Ok(printed)
+}
+
+fn run(cli: &Cli) -> Result<ExitCode> {
+    let options = cli.options.compile_options();
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    let code = if cli.scan || cli.parse || cli.make_re || !cli.test.is_empty() {
+        debug(cli, &options, &mut out)?;
+        ExitCode::SUCCESS
+    } else if filter(cli, &options, &mut out)? {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    };
+
+    out.flush()?;
+    Ok(code)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(code) => code,
+        Err(err) => {
+            if err
+                .downcast_ref::<io::Error>()
+                .is_some_and(|err| err.kind() == io::ErrorKind::BrokenPipe)
+            {
+                return ExitCode::SUCCESS;
+            }
+            eprintln!("picomatch: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
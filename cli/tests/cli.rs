@@ -0,0 +1,173 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+
+fn picomatch(args: &[&str], stdin: impl AsRef<[u8]>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_picomatch"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // The tool exits early on bad arguments, so a closed pipe is not an error.
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_ref());
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn json_lines(output: &Output) -> Vec<Value> {
+    stdout(output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+const FILES: &str = "src/lib.rs\r\nsrc/tests/a.rs\nsrc/.hidden.rs\nREADME.md\n\nsrc/bin/main.rs\n";
+
+#[test]
+fn filters_stdin_by_includes_and_excludes() {
+    let output = picomatch(&["src/**/*.rs", "!**/tests/**"], FILES);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "src/lib.rs\nsrc/bin/main.rs\n");
+
+    // Like the include side, `**` skips dotfiles unless `--dot` is set.
+    let output = picomatch(&["!**/*.rs"], FILES);
+    assert_eq!(stdout(&output), "src/.hidden.rs\nREADME.md\n");
+    let output = picomatch(&["--dot", "!**/*.rs"], FILES);
+    assert_eq!(stdout(&output), "README.md\n");
+
+    let output = picomatch(&["--invert", "src/**/*.rs", "!**/tests/**"], FILES);
+    assert_eq!(
+        stdout(&output),
+        "src/tests/a.rs\nsrc/.hidden.rs\nREADME.md\n"
+    );
+}
+
+#[test]
+fn passes_glob_options_through() {
+    let output = picomatch(&["--dot", "src/*.rs"], FILES);
+    assert_eq!(stdout(&output), "src/lib.rs\nsrc/.hidden.rs\n");
+
+    let output = picomatch(&["--nocase", "--match-base", "*.MD"], FILES);
+    assert_eq!(stdout(&output), "README.md\n");
+
+    let output = picomatch(&["-0", "*.md"], "a.md\0b.txt\0c.md");
+    assert_eq!(stdout(&output), "a.md\0c.md\0");
+}
+
+#[test]
+fn prints_non_utf8_paths_unchanged() {
+    let input: &[u8] = b"logs/caf\xe9.log\nlogs/\xff\xfe.txt\nlogs/ok.log\n";
+    let output = picomatch(&["logs/*.log"], input);
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"logs/caf\xe9.log\nlogs/ok.log\n");
+}
+
+#[test]
+fn exit_status_reports_matches_and_errors() {
+    let output = picomatch(&["*.toml"], FILES);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+
+    let output = picomatch(&["--strict-glob", "(a|b)"], FILES);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("picomatch: invalid pattern `(a|b)`"),
+        "{stderr}"
+    );
+
+    let output = picomatch(&["--no-ignore", "*"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn rejects_patterns_longer_than_max_length() {
+    let output = picomatch(&["--max-length", "2", "*.rs"], "abcdef.rs\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("picomatch: invalid pattern `*.rs`: length 4 exceeds"),
+        "{stderr}"
+    );
+
+    let output = picomatch(&["--max-length", "4", "*.rs"], "abcdef.rs\n");
+    assert_eq!(stdout(&output), "abcdef.rs\n");
+}
+
+#[test]
+fn prints_debug_json() {
+    let output = picomatch(&["--scan", "src/**/*.rs"], "");
+    let scans = json_lines(&output);
+    assert_eq!(scans[0]["base"], "src");
+    assert_eq!(scans[0]["glob"], "**/*.rs");
+    assert_eq!(scans[0]["parts"], serde_json::json!(["src", "**", "*.rs"]));
+
+    let output = picomatch(&["--make-re", "*.js", "*.ts"], "");
+    let regexes = json_lines(&output);
+    assert_eq!(regexes.len(), 2);
+    assert_eq!(regexes[0]["source"], r"^(?:(?!\.)(?=.)[^/]*?\.js)$");
+
+    let output = picomatch(&["--parse", "!a/*"], "");
+    assert_eq!(json_lines(&output)[0]["negated"], true);
+
+    let output = picomatch(
+        &["--test", "src/tests/a.rs", "src/**/*.rs", "!**/tests/**"],
+        "",
+    );
+    let report = &json_lines(&output)[0];
+    assert_eq!(report["path"], "src/tests/a.rs");
+    assert_eq!(report["selected"], false);
+    assert_eq!(report["patterns"][0]["isMatch"], true);
+    assert_eq!(report["patterns"][1]["negated"], true);
+    assert_eq!(report["patterns"][1]["isMatch"], false);
+    assert_eq!(
+        report["patterns"][1]["explanation"][1],
+        "segment 2 `tests` matched `tests`"
+    );
+}
+
+#[test]
+fn walks_directories_honouring_gitignore() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli-walk");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/generated")).unwrap();
+    fs::write(root.join(".gitignore"), "generated/\n").unwrap();
+    fs::write(root.join("src/lib.rs"), "").unwrap();
+    fs::write(root.join("src/generated/out.rs"), "").unwrap();
+    fs::write(root.join("notes.md"), "").unwrap();
+    let root = root.to_str().unwrap();
+
+    let output = picomatch(&["--walk", root, "**/*.rs"], "");
+    assert_eq!(stdout(&output), "src/lib.rs\n");
+
+    let output = picomatch(&["--walk", root, "--no-ignore", "**/*.rs"], "");
+    assert_eq!(stdout(&output), "src/lib.rs\nsrc/generated/out.rs\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn walks_non_utf8_names_unchanged() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli-walk-bytes");
+    let _ = fs::remove_dir_all(&root);
+    let dir = root.join("logs");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(OsStr::from_bytes(b"caf\xe9.log")), "").unwrap();
+    fs::write(dir.join(OsStr::from_bytes(b"caf\xe9.txt")), "").unwrap();
+    let root = root.to_str().unwrap();
+
+    let output = picomatch(&["--walk", root, "logs/*.log"], "");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"logs/caf\xe9.log\n");
+}
@@ -0,0 +1,310 @@
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::engine::{case_variants, GlobProgram, Inst};
+use crate::Matcher;
+
+// Marks a thread that has reached `Match`: it accepts whatever follows.
+const DONE: usize = usize::MAX;
+
+// Symbolic execution of a `GlobProgram`. Where `GlobProgram::run` tracks the
+// threads alive after a known input, a `State` describes them after a prefix
+// chosen one character at a time, which lets analyses step several programs
+// in lockstep. Lookaheads depend on input that has not been read yet, so each
+// thread carries the lookaheads it still has to satisfy, or for negative ones
+// avoid, as nested states that are stepped along with it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct State(BTreeSet<Thread>);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Thread {
+    pc: usize,
+    looks: BTreeSet<Look>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Look {
+    negate: bool,
+    body: State,
+}
+
+impl State {
+    fn new(threads: BTreeSet<Thread>) -> Self {
+        let done = Thread {
+            pc: DONE,
+            looks: BTreeSet::new(),
+        };
+        if threads.contains(&done) {
+            State(BTreeSet::from([done]))
+        } else {
+            State(threads)
+        }
+    }
+
+    // `Some` once the outcome no longer depends on the rest of the input.
+    fn verdict(&self) -> Option<bool> {
+        match self.0.first() {
+            None => Some(false),
+            Some(thread) if thread.pc == DONE && thread.looks.is_empty() => Some(true),
+            Some(_) => None,
+        }
+    }
+}
+
+pub(crate) struct Automaton<'a> {
+    program: Cow<'a, GlobProgram>,
+    // Globs matched against the basename restart at every separator.
+    separators: Vec<char>,
+    restarts: bool,
+}
+
+impl<'a> Automaton<'a> {
+    pub(crate) fn new(matcher: &'a Matcher) -> Option<Self> {
+        let options = matcher.options();
+        let separators = if options.match_base || options.basename {
+            if options.windows {
+                vec!['/', '\\']
+            } else {
+                vec!['/']
+            }
+        } else {
+            Vec::new()
+        };
+
+        let mut automaton = Self {
+            program: matcher.program()?,
+            separators,
+            restarts: false,
+        };
+        automaton.restarts = !automaton.seed(0, false).0.is_empty();
+        Some(automaton)
+    }
+
+    pub(crate) fn start(&self) -> State {
+        self.seed(0, true)
+    }
+
+    pub(crate) fn step(&self, state: &State, ch: char) -> State {
+        if self.separators.contains(&ch) {
+            return self.start();
+        }
+
+        // The main program is searched for rather than anchored, so it is
+        // started again at every position, as `GlobProgram::run` does.
+        let mut threads = self.advance(state, ch);
+        self.closure(0, BTreeSet::new(), false, &mut threads);
+        State::new(threads)
+    }
+
+    // A dead state can never accept again, whatever input follows.
+    pub(crate) fn is_dead(&self, state: &State) -> bool {
+        state.0.is_empty() && !self.restarts && self.separators.is_empty()
+    }
+
+    // Whether the input read so far is accepted if it ends here.
+    pub(crate) fn accepts(&self, state: &State) -> bool {
+        self.state_accepts(state) || self.pc_accepts(0)
+    }
+
+    fn seed(&self, pc: usize, at_start: bool) -> State {
+        let mut threads = BTreeSet::new();
+        self.closure(pc, BTreeSet::new(), at_start, &mut threads);
+        State::new(threads)
+    }
+
+    // Follows epsilon transitions from `pc`, stopping at instructions that
+    // consume input and at `$`, which only passes once the input ends.
+    fn closure(
+        &self,
+        pc: usize,
+        looks: BTreeSet<Look>,
+        at_start: bool,
+        threads: &mut BTreeSet<Thread>,
+    ) {
+        let mut stack = vec![(pc, looks)];
+        let mut seen = BTreeSet::new();
+
+        while let Some((pc, looks)) = stack.pop() {
+            if !seen.insert((pc, looks.clone())) {
+                continue;
+            }
+
+            match self.program.insts[pc] {
+                Inst::Split(first, second) => {
+                    stack.push((second, looks.clone()));
+                    stack.push((first, looks));
+                }
+                Inst::Jmp(target) => stack.push((target, looks)),
+                Inst::Start if at_start => stack.push((pc + 1, looks)),
+                Inst::Start => {}
+                Inst::Look { id, negate } => {
+                    let body = self.seed(self.program.looks[id], at_start);
+                    match body.verdict() {
+                        Some(matched) if matched == negate => {}
+                        Some(_) => stack.push((pc + 1, looks)),
+                        None => {
+                            let mut looks = looks;
+                            looks.insert(Look { negate, body });
+                            stack.push((pc + 1, looks));
+                        }
+                    }
+                }
+                Inst::Match => {
+                    threads.insert(Thread { pc: DONE, looks });
+                }
+                Inst::Char(_) | Inst::Class(_) | Inst::Any | Inst::End => {
+                    threads.insert(Thread { pc, looks });
+                }
+            }
+        }
+    }
+
+    fn advance(&self, state: &State, ch: char) -> BTreeSet<Thread> {
+        let mut threads = BTreeSet::new();
+
+        'threads: for thread in &state.0 {
+            if thread.pc != DONE && !self.consumes(thread.pc, ch) {
+                continue;
+            }
+
+            let mut looks = BTreeSet::new();
+            for look in &thread.looks {
+                let body = State::new(self.advance(&look.body, ch));
+                match body.verdict() {
+                    Some(matched) if matched == look.negate => continue 'threads,
+                    Some(_) => {}
+                    None => {
+                        looks.insert(Look {
+                            negate: look.negate,
+                            body,
+                        });
+                    }
+                }
+            }
+
+            if thread.pc == DONE {
+                threads.insert(Thread { pc: DONE, looks });
+            } else {
+                self.closure(thread.pc + 1, looks, false, &mut threads);
+            }
+        }
+
+        threads
+    }
+
+    fn consumes(&self, pc: usize, ch: char) -> bool {
+        match self.program.insts[pc] {
+            Inst::Char(expected) => self.program.char_eq(expected, ch),
+            Inst::Class(id) => self.program.class_matches(&self.program.classes[id], ch),
+            Inst::Any => ch != '\n',
+            _ => false,
+        }
+    }
+
+    fn state_accepts(&self, state: &State) -> bool {
+        state.0.iter().any(|thread| {
+            (thread.pc == DONE || self.pc_accepts(thread.pc))
+                && thread
+                    .looks
+                    .iter()
+                    .all(|look| self.state_accepts(&look.body) != look.negate)
+        })
+    }
+
+    // Whether `pc` reaches `Match` without consuming input at the end of a
+    // non-empty input.
+    fn pc_accepts(&self, pc: usize) -> bool {
+        let mut stack = vec![pc];
+        let mut seen = BTreeSet::new();
+
+        while let Some(pc) = stack.pop() {
+            if !seen.insert(pc) {
+                continue;
+            }
+
+            match self.program.insts[pc] {
+                Inst::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                Inst::Jmp(target) => stack.push(target),
+                Inst::End => stack.push(pc + 1),
+                Inst::Look { id, negate } => {
+                    if self.pc_accepts(self.program.looks[id]) != negate {
+                        stack.push(pc + 1);
+                    }
+                }
+                Inst::Match => return true,
+                Inst::Start | Inst::Char(_) | Inst::Class(_) | Inst::Any => {}
+            }
+        }
+
+        false
+    }
+}
+
+// Characters fall into classes that every instruction of the given automata
+// treats alike; one representative per class is enough to explore them all.
+// Representatives are picked to read well in generated paths.
+pub(crate) fn alphabet(automata: &[&Automaton<'_>]) -> Vec<char> {
+    let mut bounds = BTreeSet::from([0, '\n' as u32, '\n' as u32 + 1]);
+    let mut mark = |lo: char, hi: char, nocase: bool| {
+        let mut points = vec![(lo, hi)];
+        if nocase {
+            points.extend(
+                case_variants(lo)
+                    .into_iter()
+                    .chain(case_variants(hi))
+                    .map(|ch| (ch, ch)),
+            );
+        }
+        for (lo, hi) in points {
+            bounds.insert(lo as u32);
+            bounds.insert(hi as u32 + 1);
+        }
+    };
+
+    for automaton in automata {
+        let program = &automaton.program;
+        for inst in &program.insts {
+            match *inst {
+                Inst::Char(ch) => mark(ch, ch, program.nocase),
+                Inst::Class(id) => {
+                    for &(lo, hi) in &program.classes[id].ranges {
+                        mark(lo, hi, program.nocase);
+                    }
+                }
+                _ => {}
+            }
+        }
+        for &ch in &automaton.separators {
+            mark(ch, ch, false);
+        }
+    }
+
+    let bounds = bounds.into_iter().collect::<Vec<_>>();
+    let mut chars = bounds
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &lo)| {
+            let hi = bounds.get(index + 1).copied().unwrap_or(0x11_0000);
+            let preferred = ('a'..='z')
+                .chain('0'..='9')
+                .chain('A'..='Z')
+                .find(|&ch| (lo..hi).contains(&(ch as u32)));
+            preferred.or_else(|| (lo..hi).find_map(char::from_u32))
+        })
+        .collect::<Vec<_>>();
+    chars.sort_by_key(|ch| {
+        let rank = match ch {
+            'a'..='z' => 0,
+            '0'..='9' => 1,
+            'A'..='Z' => 2,
+            _ => 3,
+        };
+        (rank, *ch)
+    });
+    chars
+}
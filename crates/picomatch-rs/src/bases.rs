@@ -0,0 +1,210 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::compile::{collect_bracket, collect_enclosed, split_negation, split_top_level};
+use crate::{glob_covers, scan, CompileOptions, ScanOptions};
+
+// Brace alternatives a single pattern is expanded into before its base is
+// taken from the unexpanded pattern instead.
+const MAX_ALTERNATIVES: usize = 64;
+
+// The directories a watcher or walker needs to visit to see every path the
+// include patterns match: `src/**/*.ts`, `src/lib/*.js` and `test/**` give
+// `src` and `test`. Bases are taken from `scan`, after expanding braces such
+// as `{src,test}/**`, and a literal path contributes its parent directory.
+// Includes that a negation excludes entirely are dropped, and bases inside
+// another base are merged into it. The result is sorted, with `.` standing
+// for the current directory.
+pub fn glob_bases(patterns: &[&str], options: &CompileOptions) -> Vec<String> {
+    let mut includes = Vec::new();
+    let mut negations = Vec::new();
+    for pattern in patterns.iter().filter(|pattern| !pattern.is_empty()) {
+        match split_negation(pattern, options) {
+            (positive, true) => negations.push(positive),
+            (positive, false) => includes.push(positive),
+        }
+    }
+
+    let mut bases = Vec::new();
+    for include in includes {
+        for alternative in expand_braces(include, options) {
+            let excluded = negations
+                .iter()
+                .any(|negation| glob_covers(negation, &alternative, options).unwrap_or(false));
+            if !excluded {
+                bases.push(base(&alternative, options));
+            }
+        }
+    }
+
+    bases.sort_unstable();
+    bases.dedup();
+    bases
+        .iter()
+        .filter(|base| {
+            !bases
+                .iter()
+                .any(|other| other != *base && contains(other, base))
+        })
+        .cloned()
+        .collect()
+}
+
+fn base(pattern: &str, options: &CompileOptions) -> String {
+    let is_separator = |ch: char| ch == '/' || (options.windows && ch == '\\');
+    if (options.match_base || options.basename) && !pattern.chars().any(is_separator) {
+        return ".".to_string();
+    }
+
+    // `scan` always reads braces as globs.
+    let pattern = if options.nobrace {
+        escape_braces(pattern)
+    } else {
+        pattern.to_string()
+    };
+    let state = scan(
+        &pattern,
+        &ScanOptions {
+            noext: options.noextglob,
+            ..ScanOptions::default()
+        },
+    );
+    let mut base = unescape(&state.base, options);
+    if !state.is_glob && !base.ends_with('/') {
+        // A literal path names a file, which is seen from its directory.
+        base = match base.rfind('/') {
+            Some(0) => "/".to_string(),
+            Some(index) => base[..index].to_string(),
+            None => String::new(),
+        };
+    }
+
+    let base = base.strip_prefix("./").unwrap_or(&base);
+    let trimmed = base.trim_end_matches('/');
+    match (trimmed, base) {
+        ("", "") => ".".to_string(),
+        ("", _) => "/".to_string(),
+        (trimmed, _) => trimmed.to_string(),
+    }
+}
+
+// Drops escapes and, with `windows`, turns backslashes that escape nothing
+// into `/`.
+fn unescape(base: &str, options: &CompileOptions) -> String {
+    let mut result = String::with_capacity(base.len());
+    let mut chars = base.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+        match chars.peek() {
+            Some(next) if options.windows && !is_glob_char(*next) => result.push('/'),
+            Some(&next) => {
+                result.push(next);
+                chars.next();
+            }
+            None => {}
+        }
+    }
+    result
+}
+
+fn escape_braces(pattern: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + 4);
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                result.push(ch);
+                result.extend(chars.next());
+            }
+            '{' | '}' => {
+                result.push('\\');
+                result.push(ch);
+            }
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+fn is_glob_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '*' | '?' | '+' | '@' | '!' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+    )
+}
+
+// Expands every `{a,b}` brace, leaving ranges and single items alone. Falls
+// back to the pattern itself past `MAX_ALTERNATIVES`.
+fn expand_braces(pattern: &str, options: &CompileOptions) -> Vec<String> {
+    if options.nobrace {
+        return vec![pattern.to_string()];
+    }
+    let mut expanded = Vec::new();
+    if expand_into(pattern, 0, &mut expanded) {
+        expanded
+    } else {
+        vec![pattern.to_string()]
+    }
+}
+
+fn expand_into(pattern: &str, from: usize, expanded: &mut Vec<String>) -> bool {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut index = from;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => {
+                index += 2;
+                continue;
+            }
+            '[' => {
+                if let Some((_, end)) = collect_bracket(&chars, index) {
+                    index = end;
+                    continue;
+                }
+            }
+            '{' => {
+                if let Some((inner, end)) = collect_enclosed(&chars, index, '{', '}') {
+                    let branches = split_top_level(&inner, ',');
+                    if branches.len() > 1 {
+                        let prefix = chars[..index].iter().collect::<String>();
+                        let suffix = chars[end..].iter().collect::<String>();
+                        for branch in branches {
+                            let alternative = format!("{prefix}{branch}{suffix}");
+                            let resume = prefix.chars().count();
+                            if !expand_into(&alternative, resume, expanded) {
+                                return false;
+                            }
+                        }
+                        return true;
+                    }
+                    index = end;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    if expanded.len() == MAX_ALTERNATIVES {
+        return false;
+    }
+    expanded.push(pattern.to_string());
+    true
+}
+
+// Whether the directory `outer` contains `inner`, segment-wise.
+fn contains(outer: &str, inner: &str) -> bool {
+    match outer {
+        "." => !inner.starts_with('/') && inner != ".." && !inner.starts_with("../"),
+        "/" => inner.starts_with('/'),
+        _ => inner
+            .strip_prefix(outer)
+            .is_some_and(|rest| rest.starts_with('/')),
+    }
+}
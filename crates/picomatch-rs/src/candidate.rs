@@ -0,0 +1,134 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::utils::{decode_path_bytes, is_path_separator};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CandidateView {
+    pub(crate) subject: usize,
+    pub(crate) basename: (usize, usize),
+    pub(crate) extension: Option<usize>,
+    pub(crate) slashed: Option<String>,
+}
+
+// A path prepared once for matching against many `Matcher`s. Without a
+// directory hint it behaves exactly like the `&str` passed to
+// `Matcher::is_match`; with one it behaves like `Matcher::is_match_path`.
+// Only building a candidate allocates: its basename, extension and the
+// slash-terminated spelling of a directory are never recomputed per matcher.
+#[derive(Debug, Clone)]
+pub struct Candidate<'a> {
+    pub(crate) path: Cow<'a, str>,
+    pub(crate) is_dir: Option<bool>,
+    pub(crate) posix: CandidateView,
+    pub(crate) windows: CandidateView,
+}
+
+impl<'a> Candidate<'a> {
+    pub fn new(path: &'a str) -> Self {
+        Self::build(Cow::Borrowed(path), None)
+    }
+
+    pub fn with_dir_hint(path: &'a str, is_dir: bool) -> Self {
+        Self::build(Cow::Borrowed(path), Some(is_dir))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_path(path: &'a Path, is_dir: bool) -> Self {
+        Self::build(
+            decode_path_bytes(path.as_os_str().as_encoded_bytes()),
+            Some(is_dir),
+        )
+    }
+
+    pub fn from_bytes(path: &'a [u8]) -> Self {
+        Self::build(decode_path_bytes(path), None)
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn is_dir(&self) -> Option<bool> {
+        self.is_dir
+    }
+
+    pub fn basename(&self, windows: bool) -> &str {
+        let (start, end) = self.view(windows).basename;
+        &self.path[start..end]
+    }
+
+    pub fn extension(&self, windows: bool) -> Option<&str> {
+        let view = self.view(windows);
+        view.extension
+            .map(|start| &self.path[start..view.basename.1])
+    }
+
+    pub(crate) fn view(&self, windows: bool) -> &CandidateView {
+        if windows {
+            &self.windows
+        } else {
+            &self.posix
+        }
+    }
+
+    pub(crate) fn subject(&self, windows: bool) -> &str {
+        &self.path[..self.view(windows).subject]
+    }
+
+    fn build(path: Cow<'a, str>, is_dir: Option<bool>) -> Self {
+        let posix = candidate_view(&path, is_dir, false);
+        let windows = candidate_view(&path, is_dir, true);
+        Self {
+            path,
+            is_dir,
+            posix,
+            windows,
+        }
+    }
+}
+
+fn candidate_view(path: &str, is_dir: Option<bool>, windows: bool) -> CandidateView {
+    let subject = match is_dir {
+        Some(_) => trim_trailing_separators(path, windows).len(),
+        None => path.len(),
+    };
+    let basename = basename_range(&path[..subject], windows);
+    let extension = path[basename.0..basename.1]
+        .rfind('.')
+        .filter(|&index| index > 0)
+        .map(|index| basename.0 + index + 1);
+    let slashed = (is_dir == Some(true) && subject > 0).then(|| {
+        let mut slashed = String::with_capacity(subject + 1);
+        slashed.push_str(&path[..subject]);
+        slashed.push('/');
+        slashed
+    });
+
+    CandidateView {
+        subject,
+        basename,
+        extension,
+        slashed,
+    }
+}
+
+pub(crate) fn trim_trailing_separators(input: &str, windows: bool) -> &str {
+    if windows {
+        input.trim_end_matches(is_path_separator)
+    } else {
+        input.trim_end_matches('/')
+    }
+}
+
+pub(crate) fn basename_range(input: &str, windows: bool) -> (usize, usize) {
+    let is_sep = |ch: char| ch == '/' || (windows && ch == '\\');
+    let end = match input.char_indices().next_back() {
+        Some((index, ch)) if is_sep(ch) => index,
+        _ => input.len(),
+    };
+    let start = input[..end].rfind(is_sep).map_or(0, |index| index + 1);
+    (start, end)
+}
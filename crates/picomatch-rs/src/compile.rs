@@ -1,24 +1,33 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::engine::Engine;
 use crate::utils::is_path_separator;
 
+#[cfg(feature = "serde")]
 fn default_true() -> bool {
     true
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
-#[serde(default, rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct CompileOptions {
     pub bash: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub basename: bool,
     pub contains: bool,
     pub dot: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub flags: String,
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub literal_plus_quantifier: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub match_base: bool,
     pub nobrace: bool,
     pub nobracket: bool,
@@ -26,17 +35,30 @@ pub struct CompileOptions {
     pub noglobstar: bool,
     pub nocase: bool,
     pub nonegate: bool,
-    #[serde(default = "default_true")]
+    #[cfg_attr(feature = "serde", serde(default = "default_true"))]
     pub posix: bool,
     pub strict_brackets: bool,
     pub strict_slashes: bool,
     pub unescape: bool,
     pub windows: bool,
     pub regex: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strict_glob: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub engine: Engine,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub keep_quotes: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub max_length: Option<usize>,
+    // Only applies to the regex engine; the glob engine never backtracks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub backtrack_limit: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_nesting_depth: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_alternations: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_regex_size: Option<usize>,
 }
 
 impl Default for CompileOptions {
@@ -61,41 +83,55 @@ impl Default for CompileOptions {
             unescape: false,
             windows: false,
             regex: false,
+            strict_glob: false,
+            engine: Engine::Auto,
             keep_quotes: false,
             max_length: None,
+            backtrack_limit: None,
+            max_nesting_depth: None,
+            max_alternations: None,
+            max_regex_size: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ParseState {
     pub input: String,
     pub output: String,
     pub negated: bool,
     pub fastpaths: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub tokens: Option<Vec<ParseToken>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct RegexDescriptor {
     pub source: String,
     pub flags: String,
     pub output: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub state: Option<ParseState>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ParseToken {
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     pub kind: String,
     pub value: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub output: Option<String>,
+    // Byte offsets of the token in the pattern passed to `parse`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub start: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub end: usize,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -199,7 +235,7 @@ fn is_separator(ch: char, options: &CompileOptions) -> bool {
     }
 }
 
-fn collect_enclosed(
+pub(crate) fn collect_enclosed(
     chars: &[char],
     start: usize,
     open: char,
@@ -232,7 +268,7 @@ fn collect_enclosed(
     None
 }
 
-fn collect_bracket(chars: &[char], start: usize) -> Option<(String, usize)> {
+pub(crate) fn collect_bracket(chars: &[char], start: usize) -> Option<(String, usize)> {
     let mut inner = String::new();
     let mut index = start + 1;
 
@@ -282,7 +318,7 @@ fn collect_bracket(chars: &[char], start: usize) -> Option<(String, usize)> {
     None
 }
 
-fn split_top_level(input: &str, delimiter: char) -> Vec<String> {
+pub(crate) fn split_top_level(input: &str, delimiter: char) -> Vec<String> {
     let chars: Vec<char> = input.chars().collect();
     let mut parts = Vec::new();
     let mut current = String::new();
@@ -328,7 +364,7 @@ fn split_top_level(input: &str, delimiter: char) -> Vec<String> {
     parts
 }
 
-fn split_top_level_range(input: &str) -> Option<(String, String)> {
+pub(crate) fn split_top_level_range(input: &str) -> Option<(String, String)> {
     let chars: Vec<char> = input.chars().collect();
     let mut parens = 0usize;
     let mut braces = 0usize;
@@ -380,10 +416,13 @@ fn split_top_level_range(input: &str) -> Option<(String, String)> {
     Some((start, end))
 }
 
+// Numeric brace ranges longer than this are rejected rather than expanded.
+pub(crate) const MAX_RANGE_VALUES: u64 = 1024;
+
 fn expand_numeric_range(start: i64, end: i64) -> Option<String> {
     let step = if start <= end { 1 } else { -1 };
     let count = start.abs_diff(end) + 1;
-    if count > 1024 {
+    if count > MAX_RANGE_VALUES {
         return None;
     }
 
@@ -447,7 +486,7 @@ fn compile_range(start: &str, end: &str) -> Option<String> {
 const POSIX_PUNCT_OUTPUT: &str = r##"\-!"#$%&'()\*+,./:;<=>?@[\]^_`{|}~"##;
 const POSIX_PUNCT_ENGINE: &str = r##"\-!"#$%&'()\*+,./:;<=>?@\[\]^_`{|}~"##;
 
-fn posix_class_source(name: &str) -> Option<&'static str> {
+pub(crate) fn posix_class_source(name: &str) -> Option<&'static str> {
     match name {
         "alnum" => Some("a-zA-Z0-9"),
         "alpha" => Some("a-zA-Z"),
@@ -522,7 +561,7 @@ fn sanitize_nested_negation(input: &str, strip_terminal_anchor: bool) -> String
     output.to_string()
 }
 
-fn contains_magic(input: &str) -> bool {
+pub(crate) fn contains_magic(input: &str) -> bool {
     let mut escaped = false;
     for ch in input.chars() {
         if escaped {
@@ -725,7 +764,7 @@ fn compile_group(inner: &str, options: &CompileOptions) -> Option<String> {
     Some(format!("({})", alternatives.join("|")))
 }
 
-fn is_regex_escape(next: char) -> bool {
+pub(crate) fn is_regex_escape(next: char) -> bool {
     next.is_ascii_digit()
         || matches!(
             next,
@@ -1467,6 +1506,7 @@ fn push_parse_token(tokens: &mut Vec<ParseToken>, token: ParseToken) {
             );
             prev.output = Some(merged_output);
             prev.value.push_str(&token.value);
+            prev.end = token.end;
             return;
         }
     }
@@ -1474,18 +1514,28 @@ fn push_parse_token(tokens: &mut Vec<ParseToken>, token: ParseToken) {
     tokens.push(token);
 }
 
-fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
+// `skipped` is the length of the negation stripped off the pattern, so
+// token spans point into the pattern as written.
+fn parse_tokens(input: &str, skipped: usize, options: &CompileOptions) -> Vec<ParseToken> {
     let chars = input.chars().collect::<Vec<_>>();
+    let offsets = input
+        .char_indices()
+        .map(|(offset, _)| skipped + offset)
+        .chain(core::iter::once(skipped + input.len()))
+        .collect::<Vec<_>>();
     let mut tokens = vec![ParseToken {
         kind: "bos".to_string(),
         value: String::new(),
         output: Some(String::new()),
+        start: skipped,
+        end: skipped,
     }];
     let mut braces = 0usize;
     let mut parens = 0usize;
     let mut index = 0usize;
 
     while index < chars.len() {
+        let token_start = offsets[index];
         let ch = chars[index];
         let next = chars.get(index + 1).copied();
         let prev_kind = tokens
@@ -1502,6 +1552,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "brace".to_string(),
                         value: "{".to_string(),
                         output: Some("(".to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1513,6 +1565,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "brace".to_string(),
                         value: "}".to_string(),
                         output: Some(")".to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1528,6 +1582,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "comma".to_string(),
                         value: ",".to_string(),
                         output: Some(output),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1539,6 +1595,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "paren".to_string(),
                         value: "(".to_string(),
                         output: None,
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1551,6 +1609,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "paren".to_string(),
                         value: ")".to_string(),
                         output: Some(output),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1560,12 +1620,16 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "text".to_string(),
                         value: ".".to_string(),
                         output: Some(r"\.".to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     }
                 } else {
                     ParseToken {
                         kind: "dot".to_string(),
                         value: ".".to_string(),
                         output: Some(r"\.".to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     }
                 };
                 push_parse_token(&mut tokens, token);
@@ -1577,6 +1641,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "star".to_string(),
                         value: "*".to_string(),
                         output: Some(star(options)),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1592,6 +1658,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "qmark".to_string(),
                         value: "?".to_string(),
                         output: Some(output),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1602,6 +1670,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "slash".to_string(),
                         value: "/".to_string(),
                         output: Some(slash_literal(options).to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1612,6 +1682,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "plus".to_string(),
                         value: "+".to_string(),
                         output: Some(r"\+".to_string()),
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1622,6 +1694,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "text".to_string(),
                         value: "|".to_string(),
                         output: None,
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1638,6 +1712,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "text".to_string(),
                         value,
                         output: None,
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1673,6 +1749,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                         kind: "text".to_string(),
                         value,
                         output: None,
+                        start: token_start,
+                        end: offsets[index + 1],
                     },
                 );
             }
@@ -1692,6 +1770,8 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
                 kind: "maybe_slash".to_string(),
                 value: String::new(),
                 output: Some(format!("{}?", slash_literal(options))),
+                start: skipped + input.len(),
+                end: skipped + input.len(),
             },
         );
     }
@@ -1699,33 +1779,32 @@ fn parse_tokens(input: &str, options: &CompileOptions) -> Vec<ParseToken> {
     tokens
 }
 
-pub fn parse(input: &str, options: &CompileOptions) -> Option<ParseState> {
-    let mut pattern = input;
-    let mut negated = false;
-
-    if !options.nonegate {
-        let chars: Vec<char> = input.chars().collect();
-        let mut count = 0usize;
-        while count < chars.len() && chars[count] == '!' {
-            if chars.get(count + 1) == Some(&'(') && chars.get(count + 2) != Some(&'?') {
-                break;
-            }
-            count += 1;
-        }
+pub(crate) fn split_negation<'a>(input: &'a str, options: &CompileOptions) -> (&'a str, bool) {
+    if options.nonegate {
+        return (input, false);
+    }
 
-        if count > 0 {
-            negated = count % 2 == 1;
-            pattern = &input[count..];
+    let chars: Vec<char> = input.chars().collect();
+    let mut count = 0usize;
+    while count < chars.len() && chars[count] == '!' {
+        if chars.get(count + 1) == Some(&'(') && chars.get(count + 2) != Some(&'?') {
+            break;
         }
+        count += 1;
     }
 
+    (&input[count..], count % 2 == 1)
+}
+
+pub fn parse(input: &str, options: &CompileOptions) -> Option<ParseState> {
+    let (pattern, negated) = split_negation(input, options);
     let output = compile_body(pattern, options)?;
     Some(ParseState {
         input: input.to_string(),
         output,
         negated,
         fastpaths: false,
-        tokens: Some(parse_tokens(pattern, options)),
+        tokens: Some(parse_tokens(pattern, input.len() - pattern.len(), options)),
     })
 }
 
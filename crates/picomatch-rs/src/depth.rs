@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::compile::{collect_bracket, collect_enclosed, split_negation, split_top_level};
+use crate::CompileOptions;
+
+// A number of path segments, where `**` makes it unbounded. Picomatch
+// reports the same values as numbers, with `Infinity` for `Unbounded`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Depth {
+    Finite(usize),
+    Unbounded,
+}
+
+impl Depth {
+    pub fn is_unbounded(self) -> bool {
+        self == Depth::Unbounded
+    }
+
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Depth::Finite(depth) => depth as f64,
+            Depth::Unbounded => f64::INFINITY,
+        }
+    }
+}
+
+impl Default for Depth {
+    fn default() -> Self {
+        Depth::Finite(0)
+    }
+}
+
+impl fmt::Display for Depth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Depth::Finite(depth) => write!(f, "{depth}"),
+            Depth::Unbounded => f.write_str("unbounded"),
+        }
+    }
+}
+
+impl Add for Depth {
+    type Output = Depth;
+
+    fn add(self, other: Depth) -> Depth {
+        match (self, other) {
+            (Depth::Finite(left), Depth::Finite(right)) => {
+                Depth::Finite(left.saturating_add(right))
+            }
+            _ => Depth::Unbounded,
+        }
+    }
+}
+
+impl AddAssign for Depth {
+    fn add_assign(&mut self, other: Depth) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Depth {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_f64())
+    }
+}
+
+// The most path segments a path matched by `pattern` can have, which bounds
+// how deep a directory walker has to descend: `src/*.js` needs 2, while
+// `src/**/*.js` is unbounded. Unlike `ScanState::max_depth`, the whole pattern
+// is considered, including slashes inside braces and extglobs. Negated
+// patterns, patterns matched against the basename and patterns under `bash`,
+// where `*` crosses slashes, or `contains`, where a match can start at any
+// depth, are unbounded.
+pub fn max_depth(pattern: &str, options: &CompileOptions) -> Depth {
+    let (positive, negated) = split_negation(pattern, options);
+    if negated || options.bash || options.contains {
+        return Depth::Unbounded;
+    }
+
+    let positive = positive.strip_prefix("./").unwrap_or(positive);
+    let chars = positive.trim_end_matches('/').chars().collect::<Vec<_>>();
+    if (options.match_base || options.basename) && !chars.contains(&'/') {
+        return Depth::Unbounded;
+    }
+    segments(&chars, options)
+}
+
+// Counts the segments of a glob, taking for every brace or extglob the
+// branch that spans the most of them.
+fn segments(chars: &[char], options: &CompileOptions) -> Depth {
+    let is_separator = |ch: char| ch == '/' || (options.windows && ch == '\\');
+    let mut depth = Depth::Finite(1);
+    let mut segment_start = 0usize;
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let group = match ch {
+            '\\' if !options.windows => {
+                index += 2;
+                continue;
+            }
+            '[' if !options.nobracket => {
+                if let Some((_, end)) = collect_bracket(chars, index) {
+                    index = end;
+                    continue;
+                }
+                None
+            }
+            '{' if !options.nobrace => collect_enclosed(chars, index, '{', '}')
+                .map(|(inner, end)| (split_top_level(&inner, ','), end)),
+            '(' => collect_enclosed(chars, index, '(', ')')
+                .map(|(inner, end)| (split_top_level(&inner, '|'), end)),
+            _ => None,
+        };
+
+        if let Some((branches, end)) = group {
+            // A branch of `n` segments adds `n - 1` separators.
+            let widest = branches
+                .iter()
+                .map(|branch| segments(&branch.chars().collect::<Vec<_>>(), options))
+                .max()
+                .unwrap_or_default();
+            depth += match widest {
+                Depth::Finite(width) => Depth::Finite(width.saturating_sub(1)),
+                Depth::Unbounded => Depth::Unbounded,
+            };
+            index = end;
+            continue;
+        }
+
+        if is_separator(ch) {
+            if is_globstar(&chars[segment_start..index], options) {
+                return Depth::Unbounded;
+            }
+            depth += Depth::Finite(1);
+            segment_start = index + 1;
+        }
+        index += 1;
+    }
+
+    if is_globstar(&chars[segment_start..], options) {
+        return Depth::Unbounded;
+    }
+    depth
+}
+
+fn is_globstar(segment: &[char], options: &CompileOptions) -> bool {
+    !options.noglobstar && segment.len() >= 2 && segment.iter().all(|&ch| ch == '*')
+}
@@ -0,0 +1,617 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+// `Auto` runs every pattern on the regex engine when the `regex` feature is
+// enabled, even patterns the glob engine accepts; it only uses the glob
+// engine in builds without that feature. `Glob` must be chosen explicitly to
+// get matching that never backtracks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Engine {
+    #[default]
+    Auto,
+    Regex,
+    Glob,
+}
+
+// Nested groups and counted repeats beyond these bounds are left to the regex
+// engine rather than growing the program without limit.
+const MAX_DEPTH: usize = 256;
+const MAX_REPEAT: u32 = 1000;
+
+// The glob engine executes the source produced by `make_re` directly. Only the
+// small set of constructs the glob compiler emits is understood, and matching
+// runs as a breadth-first simulation, so time is linear in the input for every
+// pattern without unbounded lookaheads and no input can trigger backtracking.
+#[derive(Debug, Clone)]
+pub(crate) struct GlobProgram {
+    pub(crate) insts: Vec<Inst>,
+    pub(crate) classes: Vec<Class>,
+    pub(crate) looks: Vec<usize>,
+    pub(crate) nocase: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Inst {
+    Char(char),
+    Class(usize),
+    Any,
+    Split(usize, usize),
+    Jmp(usize),
+    Start,
+    End,
+    Look { id: usize, negate: bool },
+    Match,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Class {
+    pub(crate) ranges: Vec<(char, char)>,
+    pub(crate) negated: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Char(char),
+    Class(Class),
+    Any,
+    Start,
+    End,
+    Look(Box<Node>, bool),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat(Box<Node>, u32, Option<u32>),
+}
+
+impl GlobProgram {
+    pub(crate) fn new(source: &str, flags: &str) -> Option<Self> {
+        let mut parser = Parser {
+            chars: source.chars().collect(),
+            index: 0,
+            depth: 0,
+        };
+        let node = parser.parse_alt()?;
+        if parser.index != parser.chars.len() {
+            return None;
+        }
+
+        let mut program = Self {
+            insts: Vec::new(),
+            classes: Vec::new(),
+            looks: Vec::new(),
+            nocase: flags.contains('i'),
+        };
+        let mut pending = Vec::new();
+        program.emit(&node, &mut pending, 0)?;
+        program.insts.push(Inst::Match);
+
+        // Lookahead bodies are emitted after the main program, each ending in
+        // its own `Match`, and run on demand from the position they guard.
+        while let Some((id, node)) = pending.pop() {
+            program.looks[id] = program.insts.len();
+            program.emit(&node, &mut pending, 0)?;
+            program.insts.push(Inst::Match);
+        }
+
+        Some(program)
+    }
+
+    pub(crate) fn is_match(&self, input: &str) -> bool {
+        let mut cache = BTreeMap::new();
+        self.run(input, 0, 0, false, &mut cache)
+    }
+
+    fn emit(&mut self, node: &Node, pending: &mut Vec<(usize, Node)>, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        match node {
+            Node::Char(ch) => self.insts.push(Inst::Char(*ch)),
+            Node::Class(class) => {
+                self.insts.push(Inst::Class(self.classes.len()));
+                self.classes.push(class.clone());
+            }
+            Node::Any => self.insts.push(Inst::Any),
+            Node::Start => self.insts.push(Inst::Start),
+            Node::End => self.insts.push(Inst::End),
+            Node::Look(inner, negate) => {
+                let id = self.looks.len();
+                self.looks.push(0);
+                pending.push((id, (**inner).clone()));
+                self.insts.push(Inst::Look {
+                    id,
+                    negate: *negate,
+                });
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    self.emit(node, pending, depth + 1)?;
+                }
+            }
+            Node::Alt(nodes) => {
+                let mut jumps = Vec::new();
+                for (index, node) in nodes.iter().enumerate() {
+                    if index + 1 == nodes.len() {
+                        self.emit(node, pending, depth + 1)?;
+                        break;
+                    }
+
+                    let split = self.placeholder();
+                    self.emit(node, pending, depth + 1)?;
+                    jumps.push(self.placeholder());
+                    self.insts[split] = Inst::Split(split + 1, self.insts.len());
+                }
+
+                let end = self.insts.len();
+                for jump in jumps {
+                    self.insts[jump] = Inst::Jmp(end);
+                }
+            }
+            Node::Repeat(inner, min, max) => {
+                for _ in 0..*min {
+                    self.emit(inner, pending, depth + 1)?;
+                }
+
+                match max {
+                    None => {
+                        let split = self.placeholder();
+                        self.emit(inner, pending, depth + 1)?;
+                        self.insts.push(Inst::Jmp(split));
+                        self.insts[split] = Inst::Split(split + 1, self.insts.len());
+                    }
+                    Some(max) => {
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.placeholder());
+                            self.emit(inner, pending, depth + 1)?;
+                        }
+
+                        let end = self.insts.len();
+                        for split in splits {
+                            self.insts[split] = Inst::Split(split + 1, end);
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    fn placeholder(&mut self) -> usize {
+        self.insts.push(Inst::Jmp(0));
+        self.insts.len() - 1
+    }
+
+    fn run(
+        &self,
+        input: &str,
+        start_pc: usize,
+        start: usize,
+        anchored: bool,
+        cache: &mut BTreeMap<(usize, usize), bool>,
+    ) -> bool {
+        let mut current = Threads::new(self.insts.len());
+        let mut next = Threads::new(self.insts.len());
+        let mut stack = Vec::new();
+        let mut pos = start;
+        let mut chars = input[start..].chars();
+
+        loop {
+            if (!anchored || pos == start)
+                && self.add(&mut current, &mut stack, start_pc, input, pos, cache)
+            {
+                return true;
+            }
+
+            if anchored && current.is_dead() {
+                return false;
+            }
+
+            let Some(ch) = chars.next() else {
+                return false;
+            };
+            let next_pos = pos + ch.len_utf8();
+
+            next.clear();
+            for index in 0..current.len() {
+                let pc = current.get(index);
+                let consumed = match self.insts[pc] {
+                    Inst::Char(expected) => self.char_eq(expected, ch),
+                    Inst::Class(id) => self.class_matches(&self.classes[id], ch),
+                    Inst::Any => ch != '\n',
+                    _ => false,
+                };
+
+                if consumed && self.add(&mut next, &mut stack, pc + 1, input, next_pos, cache) {
+                    return true;
+                }
+            }
+
+            mem::swap(&mut current, &mut next);
+            pos = next_pos;
+        }
+    }
+
+    fn add(
+        &self,
+        threads: &mut Threads,
+        stack: &mut Vec<usize>,
+        pc: usize,
+        input: &str,
+        pos: usize,
+        cache: &mut BTreeMap<(usize, usize), bool>,
+    ) -> bool {
+        stack.clear();
+        stack.push(pc);
+
+        while let Some(pc) = stack.pop() {
+            if !threads.insert(pc) {
+                continue;
+            }
+
+            match self.insts[pc] {
+                Inst::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                Inst::Jmp(target) => stack.push(target),
+                Inst::Start if pos == 0 => stack.push(pc + 1),
+                Inst::End if pos == input.len() => stack.push(pc + 1),
+                Inst::Look { id, negate } => {
+                    // Lookaheads run a nested simulation, which needs its own
+                    // scratch stack; the outer one is restored afterwards.
+                    let saved = mem::take(stack);
+                    let matched = self.look(id, input, pos, cache);
+                    *stack = saved;
+                    if matched != negate {
+                        stack.push(pc + 1);
+                    }
+                }
+                Inst::Match => return true,
+                Inst::Char(_) | Inst::Class(_) | Inst::Any => threads.live += 1,
+                Inst::Start | Inst::End => {}
+            }
+        }
+
+        false
+    }
+
+    fn look(
+        &self,
+        id: usize,
+        input: &str,
+        pos: usize,
+        cache: &mut BTreeMap<(usize, usize), bool>,
+    ) -> bool {
+        if let Some(&matched) = cache.get(&(id, pos)) {
+            return matched;
+        }
+
+        let matched = self.run(input, self.looks[id], pos, true, cache);
+        cache.insert((id, pos), matched);
+        matched
+    }
+
+    pub(crate) fn char_eq(&self, expected: char, actual: char) -> bool {
+        expected == actual
+            || (self.nocase && case_variants(actual).contains(&expected))
+            || (self.nocase && case_variants(expected).contains(&actual))
+    }
+
+    pub(crate) fn class_matches(&self, class: &Class, ch: char) -> bool {
+        let contains = |ch: char| class.ranges.iter().any(|&(lo, hi)| lo <= ch && ch <= hi);
+        let found = if self.nocase {
+            case_variants(ch).into_iter().any(contains)
+        } else {
+            contains(ch)
+        };
+        found != class.negated
+    }
+}
+
+pub(crate) fn case_variants(ch: char) -> [char; 3] {
+    [
+        ch,
+        single_char(ch.to_lowercase()).unwrap_or(ch),
+        single_char(ch.to_uppercase()).unwrap_or(ch),
+    ]
+}
+
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let ch = chars.next()?;
+    chars.next().is_none().then_some(ch)
+}
+
+struct Threads {
+    dense: Vec<usize>,
+    sparse: Vec<usize>,
+    live: usize,
+}
+
+impl Threads {
+    fn new(size: usize) -> Self {
+        Self {
+            dense: Vec::with_capacity(size),
+            sparse: vec![0; size],
+            live: 0,
+        }
+    }
+
+    fn insert(&mut self, pc: usize) -> bool {
+        let slot = self.sparse[pc];
+        if slot < self.dense.len() && self.dense[slot] == pc {
+            return false;
+        }
+        self.sparse[pc] = self.dense.len();
+        self.dense.push(pc);
+        true
+    }
+
+    fn get(&self, index: usize) -> usize {
+        self.dense[index]
+    }
+
+    fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    fn is_dead(&self) -> bool {
+        self.live == 0
+    }
+
+    fn clear(&mut self) {
+        self.dense.clear();
+        self.live = 0;
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    index: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let matches = expected
+            .chars()
+            .enumerate()
+            .all(|(offset, ch)| self.chars.get(self.index + offset) == Some(&ch));
+        if matches {
+            self.index += expected.chars().count();
+        }
+        matches
+    }
+
+    fn parse_alt(&mut self) -> Option<Node> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return None;
+        }
+
+        let mut alternatives = vec![self.parse_concat()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_concat()?);
+        }
+
+        self.depth -= 1;
+        Some(if alternatives.len() == 1 {
+            alternatives.pop()?
+        } else {
+            Node::Alt(alternatives)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Option<Node> {
+        let mut nodes = Vec::new();
+
+        while let Some(ch) = self.peek() {
+            if matches!(ch, '|' | ')') {
+                break;
+            }
+
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_repeat(atom)?);
+        }
+
+        Some(Node::Concat(nodes))
+    }
+
+    fn parse_atom(&mut self) -> Option<Node> {
+        let ch = self.peek()?;
+        self.index += 1;
+
+        match ch {
+            '(' => {
+                let node = if self.eat_str("?:") {
+                    self.parse_alt()?
+                } else if self.eat_str("?=") {
+                    Node::Look(Box::new(self.parse_alt()?), false)
+                } else if self.eat_str("?!") {
+                    Node::Look(Box::new(self.parse_alt()?), true)
+                } else if self.peek() == Some('?') {
+                    return None;
+                } else {
+                    self.parse_alt()?
+                };
+                self.eat(')').then_some(node)
+            }
+            '[' => self.parse_class().map(Node::Class),
+            '.' => Some(Node::Any),
+            '^' => Some(Node::Start),
+            '$' => Some(Node::End),
+            '\\' => self.parse_escape().map(Node::Char),
+            '*' | '+' | '?' | '{' | ')' | ']' | '}' => None,
+            _ => Some(Node::Char(ch)),
+        }
+    }
+
+    fn parse_repeat(&mut self, atom: Node) -> Option<Node> {
+        let mut node = atom;
+
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    let start = self.index;
+                    match self.parse_counted() {
+                        Some(bounds) => {
+                            // Counted repeats were already consumed.
+                            node = Node::Repeat(Box::new(node), bounds.0, bounds.1);
+                            self.eat('?');
+                            continue;
+                        }
+                        None => {
+                            self.index = start;
+                            return None;
+                        }
+                    }
+                }
+                _ => return Some(node),
+            };
+
+            if matches!(node, Node::Start | Node::End | Node::Look(..)) {
+                return None;
+            }
+
+            self.index += 1;
+            self.eat('?');
+            node = Node::Repeat(Box::new(node), min, max);
+        }
+    }
+
+    fn parse_counted(&mut self) -> Option<(u32, Option<u32>)> {
+        self.eat('{').then_some(())?;
+        let min = self.parse_number()?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.parse_number()?)
+            }
+        } else {
+            Some(min)
+        };
+        self.eat('}').then_some(())?;
+
+        if max.is_some_and(|max| max < min) || min > MAX_REPEAT || max.unwrap_or(0) > MAX_REPEAT {
+            return None;
+        }
+
+        Some((min, max))
+    }
+
+    fn parse_number(&mut self) -> Option<u32> {
+        let start = self.index;
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.index += 1;
+        }
+        self.chars[start..self.index]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+
+    fn parse_class(&mut self) -> Option<Class> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+
+        loop {
+            let ch = self.peek()?;
+            self.index += 1;
+
+            let lo = match ch {
+                ']' if !first => break,
+                '[' => return None,
+                '&' | '~' | '-' if self.peek() == Some(ch) => return None,
+                '\\' => self.parse_escape()?,
+                _ => ch,
+            };
+            first = false;
+
+            if self.peek() == Some('-') && self.chars.get(self.index + 1) != Some(&']') {
+                self.index += 1;
+                let hi = match self.peek()? {
+                    '\\' => {
+                        self.index += 1;
+                        self.parse_escape()?
+                    }
+                    '[' => return None,
+                    hi => {
+                        self.index += 1;
+                        hi
+                    }
+                };
+                if hi < lo {
+                    return None;
+                }
+                ranges.push((lo, hi));
+            } else {
+                ranges.push((lo, lo));
+            }
+        }
+
+        Some(Class { ranges, negated })
+    }
+
+    fn parse_escape(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.index += 1;
+
+        match ch {
+            'n' => Some('\n'),
+            't' => Some('\t'),
+            'r' => Some('\r'),
+            'f' => Some('\u{c}'),
+            'v' => Some('\u{b}'),
+            'x' => self.parse_hex(2),
+            'u' => self.parse_hex(4),
+            ch if ch.is_ascii_alphanumeric() => None,
+            ch => Some(ch),
+        }
+    }
+
+    fn parse_hex(&mut self, digits: usize) -> Option<char> {
+        let braced = self.eat('{');
+        let start = self.index;
+        while self.peek().is_some_and(|ch| ch.is_ascii_hexdigit())
+            && (braced || self.index - start < digits)
+        {
+            self.index += 1;
+        }
+
+        if (!braced && self.index - start != digits) || (braced && !self.eat('}')) {
+            return None;
+        }
+
+        let value = self.chars[start..self.index].iter().collect::<String>();
+        char::from_u32(u32::from_str_radix(&value, 16).ok()?)
+    }
+}
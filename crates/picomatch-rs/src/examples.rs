@@ -0,0 +1,495 @@
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::automaton::{alphabet, Automaton};
+use crate::candidate::basename_range;
+use crate::compile::{
+    collect_bracket, collect_enclosed, contains_magic, posix_class_source, split_negation,
+    split_top_level, split_top_level_range,
+};
+use crate::{CompileOptions, MatchError, Matcher};
+
+// Variants kept for each brace or extglob body, so nested groups stay small.
+const MAX_VARIANTS: usize = 16;
+
+// Program states explored when the pattern itself suggests too few matches.
+const MAX_STATES: usize = 2_000;
+
+// Readable stand-ins for the characters a class may stand for.
+const PREFERRED: &str = "abcxyz019ABZ_-";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Examples {
+    pub matches: Vec<String>,
+    pub near_misses: Vec<String>,
+}
+
+pub(crate) fn examples(
+    matcher: &Matcher,
+    glob: &str,
+    options: &CompileOptions,
+    count: usize,
+) -> Result<Examples, MatchError> {
+    let (positive, _) = split_negation(glob, options);
+    let chars = positive.chars().collect::<Vec<_>>();
+
+    // A glob always matches its own spelling, which says nothing about it.
+    let usable = |path: &str| !path.is_empty() && (path != glob || !contains_magic(glob));
+
+    let mut examples = Examples::default();
+    let mut seen = BTreeSet::new();
+    let mut misses = Vec::new();
+
+    for path in variants(&chars, true, options) {
+        if examples.matches.len() == count {
+            break;
+        }
+        if !usable(&path) || !seen.insert(path.clone()) {
+            continue;
+        }
+        if matcher.is_match(&path)? {
+            examples.matches.push(path);
+        } else {
+            misses.push(path);
+        }
+    }
+
+    if examples.matches.len() < count {
+        search(matcher, count, &usable, &mut seen, &mut examples.matches)?;
+    }
+
+    // Spellings of the pattern that failed come first: they are the edge
+    // cases, such as dotfiles or empty segments, the pattern leaves out.
+    examples.near_misses = misses.into_iter().take(count).collect();
+    let edits = examples
+        .matches
+        .iter()
+        .flat_map(|path| edits(path, options))
+        .collect::<Vec<_>>();
+    for path in edits {
+        if examples.near_misses.len() == count {
+            break;
+        }
+        if usable(&path) && seen.insert(path.clone()) && !matcher.is_match(&path)? {
+            examples.near_misses.push(path);
+        }
+    }
+
+    Ok(examples)
+}
+
+// Spells a pattern out piece by piece, each piece listing the texts it may
+// stand for with the plainest first. The plainest spelling comes first, then
+// spellings that change one piece at a time, then the one taking the last
+// choice of every piece, which nests `**` deepest.
+fn variants(chars: &[char], at_start: bool, options: &CompileOptions) -> Vec<String> {
+    let mut pieces: Vec<Vec<String>> = Vec::new();
+    let mut literal = String::new();
+    let mut at_start = at_start;
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        if let Some((choices, end)) = piece(chars, index, at_start, options) {
+            if !literal.is_empty() {
+                pieces.push(vec![core::mem::take(&mut literal)]);
+            }
+            pieces.push(choices);
+            at_start = chars[end - 1] == '/';
+            index = end;
+            continue;
+        }
+
+        let ch = match chars[index] {
+            '\\' if index + 1 < chars.len() => {
+                index += 1;
+                chars[index]
+            }
+            ch => ch,
+        };
+        literal.push(ch);
+        at_start = ch == '/';
+        index += 1;
+    }
+    if !literal.is_empty() {
+        pieces.push(vec![literal]);
+    }
+
+    let plainest = pieces
+        .iter()
+        .map(|choices| choices[0].as_str())
+        .collect::<String>();
+    let mut spellings = vec![plainest];
+    for (position, choices) in pieces.iter().enumerate() {
+        for choice in &choices[1..] {
+            let spelling = pieces
+                .iter()
+                .enumerate()
+                .map(|(other, others)| {
+                    if other == position {
+                        choice.as_str()
+                    } else {
+                        others[0].as_str()
+                    }
+                })
+                .collect::<String>();
+            spellings.push(spelling);
+        }
+    }
+    spellings.push(
+        pieces
+            .iter()
+            .filter_map(|choices| choices.last())
+            .map(String::as_str)
+            .collect(),
+    );
+
+    dedupe(spellings)
+}
+
+// The texts a wildcard, class, brace or extglob starting at `index` may stand
+// for, and where it ends. `None` leaves the character to be read literally.
+fn piece(
+    chars: &[char],
+    index: usize,
+    at_start: bool,
+    options: &CompileOptions,
+) -> Option<(Vec<String>, usize)> {
+    let ch = chars[index];
+    let next = chars.get(index + 1).copied();
+
+    if matches!(ch, '@' | '!' | '+' | '*' | '?') && next == Some('(') && !options.noextglob {
+        if let Some((inner, end)) = collect_enclosed(chars, index + 1, '(', ')') {
+            return Some((extglob_choices(ch, &inner, at_start, options), end));
+        }
+    }
+
+    match ch {
+        '*' => {
+            let end = index + chars[index..].iter().take_while(|&&ch| ch == '*').count();
+            let whole_segment = at_start && matches!(chars.get(end), None | Some('/'));
+            if end - index > 1 && whole_segment && !options.noglobstar {
+                let slash = chars.get(end) == Some(&'/');
+                let mut choices = ["", "a", ".a", "a/b/c"]
+                    .iter()
+                    .map(|dirs| match (slash, dirs.is_empty()) {
+                        (true, false) => format!("{dirs}/"),
+                        _ => dirs.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                if slash {
+                    // An empty segment, as in `a//b`.
+                    choices.insert(3, "/".to_string());
+                }
+                return Some((choices, end + usize::from(slash)));
+            }
+
+            let edge = if at_start { ".a" } else { "a.b" };
+            Some((strings(&["a", "abc", "", edge]), end))
+        }
+        '?' => Some((strings(&["a", "z", "."]), index + 1)),
+        '[' if !options.nobracket => {
+            let (inner, end) = collect_bracket(chars, index)?;
+            Some((class_choices(&inner), end))
+        }
+        '{' if !options.nobrace => {
+            let (inner, end) = collect_enclosed(chars, index, '{', '}')?;
+            if let Some((start, stop)) = split_top_level_range(&inner) {
+                return Some((range_choices(&start, &stop)?, end));
+            }
+
+            let branches = split_top_level(&inner, ',');
+            if branches.len() < 2 {
+                return None;
+            }
+            Some((alternatives(&branches, at_start, options), end))
+        }
+        '(' => {
+            let (inner, end) = collect_enclosed(chars, index, '(', ')')?;
+            Some((extglob_choices('@', &inner, at_start, options), end))
+        }
+        _ => None,
+    }
+}
+
+fn extglob_choices(
+    operator: char,
+    inner: &str,
+    at_start: bool,
+    options: &CompileOptions,
+) -> Vec<String> {
+    let branches = split_top_level(inner, '|');
+    let mut choices = alternatives(&branches, at_start, options);
+    let first = choices[0].clone();
+
+    match operator {
+        '?' => choices.push(String::new()),
+        '*' => {
+            choices.push(format!("{first}{first}"));
+            choices.push(String::new());
+        }
+        '+' => choices.push(format!("{first}{first}")),
+        '!' => {
+            // Anything but the branches; the branches themselves are kept as
+            // near misses.
+            let mut others = vec!["x".to_string(), format!("{first}x")];
+            others.append(&mut choices);
+            others.push(String::new());
+            choices = others;
+        }
+        _ => {}
+    }
+
+    dedupe(choices)
+}
+
+// The plainest spelling of every branch first, then the others.
+fn alternatives(branches: &[String], at_start: bool, options: &CompileOptions) -> Vec<String> {
+    let spelled = branches
+        .iter()
+        .map(|branch| variants(&branch.chars().collect::<Vec<_>>(), at_start, options))
+        .collect::<Vec<_>>();
+    let rounds = spelled.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut choices = (0..rounds)
+        .flat_map(|round| {
+            spelled
+                .iter()
+                .filter_map(move |spellings| spellings.get(round))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if choices.is_empty() {
+        choices.push(String::new());
+    }
+    choices.truncate(MAX_VARIANTS);
+    dedupe(choices)
+}
+
+// Both ends and the middle of a brace range, then the value just past it.
+fn range_choices(start: &str, stop: &str) -> Option<Vec<String>> {
+    if let (Ok(left), Ok(right)) = (start.parse::<i64>(), stop.parse::<i64>()) {
+        let middle = ((i128::from(left) + i128::from(right)) / 2) as i64;
+        let past = left.max(right).checked_add(1)?;
+        return Some(dedupe(vec![
+            start.to_string(),
+            stop.to_string(),
+            middle.to_string(),
+            past.to_string(),
+        ]));
+    }
+
+    let mut start_chars = start.chars();
+    let mut stop_chars = stop.chars();
+    let (left, right) = (start_chars.next()?, stop_chars.next()?);
+    if start_chars.next().is_some() || stop_chars.next().is_some() {
+        return None;
+    }
+
+    let mut choices = vec![left.to_string(), right.to_string()];
+    if let Some(past) = char::from_u32(left.max(right) as u32 + 1) {
+        choices.push(past.to_string());
+    }
+    Some(dedupe(choices))
+}
+
+// A few members of a bracket class, expanding POSIX classes through
+// `posix_class_source`. Negated classes yield non-members first, then one
+// member as a near miss.
+fn class_choices(inner: &str) -> Vec<String> {
+    let (negated, body) = match inner.strip_prefix(['!', '^']) {
+        Some(body) => (true, body),
+        None => (false, inner),
+    };
+    let ranges = class_ranges(body);
+    let contains = |ch: char| ranges.iter().any(|&(lo, hi)| (lo..=hi).contains(&ch));
+
+    let mut members = ranges
+        .iter()
+        .map(|&(lo, hi)| pick(lo, hi))
+        .chain(ranges.last().map(|&(_, hi)| hi))
+        .map(String::from)
+        .collect::<Vec<_>>();
+    members = dedupe(members);
+    members.truncate(3);
+
+    let mut choices = if negated {
+        let mut others = PREFERRED
+            .chars()
+            .filter(|&ch| !contains(ch))
+            .take(2)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        others.extend(members.into_iter().take(1));
+        others
+    } else {
+        members
+    };
+    if choices.is_empty() {
+        choices.push("a".to_string());
+    }
+    choices
+}
+
+fn class_ranges(body: &str) -> Vec<(char, char)> {
+    let chars = body.chars().collect::<Vec<_>>();
+    let mut ranges = Vec::new();
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        if chars[index] == '[' && chars.get(index + 1) == Some(&':') {
+            let rest = chars[index + 2..].iter().collect::<String>();
+            if let Some(source) = rest
+                .find(":]")
+                .and_then(|end| Some((posix_class_source(&rest[..end])?, end)))
+            {
+                ranges.extend(class_ranges(source.0));
+                index += 2 + rest[..source.1].chars().count() + 2;
+                continue;
+            }
+        }
+
+        let (lo, after) = class_char(&chars, index);
+        if chars.get(after) == Some(&'-') && after + 1 < chars.len() {
+            let (hi, end) = class_char(&chars, after + 1);
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            index = end;
+        } else {
+            ranges.push((lo, lo));
+            index = after;
+        }
+    }
+
+    ranges
+}
+
+// Reads one class member, resolving the escapes POSIX class sources use.
+fn class_char(chars: &[char], index: usize) -> (char, usize) {
+    if chars[index] != '\\' || index + 1 == chars.len() {
+        return (chars[index], index + 1);
+    }
+
+    let escaped = match chars[index + 1] {
+        'x' => {
+            let hex = chars[index + 2..].iter().take(2).collect::<String>();
+            if let Some(ch) = u32::from_str_radix(&hex, 16)
+                .ok()
+                .filter(|_| hex.len() == 2)
+                .and_then(char::from_u32)
+            {
+                return (ch, index + 4);
+            }
+            'x'
+        }
+        't' => '\t',
+        'n' => '\n',
+        'r' => '\r',
+        'v' => '\u{b}',
+        'f' => '\u{c}',
+        other => other,
+    };
+    (escaped, index + 2)
+}
+
+fn pick(lo: char, hi: char) -> char {
+    PREFERRED
+        .chars()
+        .find(|ch| (lo..=hi).contains(ch))
+        .or_else(|| (lo..=hi).find(|&ch| ch.is_ascii_graphic() && ch != '/'))
+        .unwrap_or(lo)
+}
+
+// Small edits that usually turn a matching path into one that fails: a
+// changed last character, a longer or hidden basename, a segment too many or
+// too few, a trailing slash or a different case.
+fn edits(path: &str, options: &CompileOptions) -> Vec<String> {
+    let (start, _) = basename_range(path, options.windows);
+    let (dir, base) = path.split_at(start);
+    let mut edits = Vec::new();
+
+    if let Some(last) = path.chars().next_back() {
+        let stem = &path[..path.len() - last.len_utf8()];
+        edits.push(format!("{stem}{}", if last == 'x' { 'y' } else { 'x' }));
+    }
+    edits.push(format!("{path}x"));
+    edits.push(format!("{dir}.{base}"));
+    edits.push(format!("x/{path}"));
+    edits.push(format!("{path}/x"));
+    if let Some((_, rest)) = path.split_once('/') {
+        edits.push(rest.to_string());
+    }
+    edits.push(format!("{path}/"));
+    edits.push(path.to_uppercase());
+    edits
+}
+
+// Walks the compiled program breadth-first for matches the pattern's own
+// spelling did not suggest.
+fn search(
+    matcher: &Matcher,
+    count: usize,
+    usable: &impl Fn(&str) -> bool,
+    seen: &mut BTreeSet<String>,
+    matches: &mut Vec<String>,
+) -> Result<(), MatchError> {
+    let Some(automaton) = Automaton::new(matcher) else {
+        return Ok(());
+    };
+    let chars = alphabet(&[&automaton])
+        .into_iter()
+        .filter(|ch| !ch.is_control())
+        .collect::<Vec<_>>();
+
+    let start = automaton.start();
+    let mut states = BTreeSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, String::new())]);
+
+    while let Some((state, path)) = queue.pop_front() {
+        for &ch in &chars {
+            let next = automaton.step(&state, ch);
+            if automaton.is_dead(&next) {
+                continue;
+            }
+
+            let mut path = path.clone();
+            path.push(ch);
+            if automaton.accepts(&next)
+                && usable(&path)
+                && seen.insert(path.clone())
+                && matcher.is_match(&path)?
+            {
+                matches.push(path.clone());
+                if matches.len() == count {
+                    return Ok(());
+                }
+            }
+
+            if states.len() < MAX_STATES && states.insert(next.clone()) {
+                queue.push_back((next, path));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn strings(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|text| text.to_string()).collect()
+}
+
+fn dedupe(items: Vec<String>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    items
+        .into_iter()
+        .filter(|item| seen.insert(item.clone()))
+        .collect()
+}
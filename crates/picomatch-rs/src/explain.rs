@@ -0,0 +1,486 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::candidate::{basename_range, trim_trailing_separators};
+use crate::compile::{collect_bracket, split_negation};
+use crate::{compile_matcher, CompileOptions, MatchError, Matcher};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Explanation {
+    pub glob: String,
+    pub input: String,
+    pub is_match: bool,
+    pub steps: Vec<ExplainStep>,
+}
+
+// Segment indexes are zero-based; the `Display` output numbers them from one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "camelCase"))]
+pub enum ExplainStep {
+    Literal,
+    Basename {
+        basename: String,
+    },
+    Matched {
+        index: usize,
+        glob: String,
+        input: String,
+    },
+    Mismatch {
+        index: usize,
+        glob: String,
+        input: String,
+    },
+    MissingInput {
+        index: usize,
+        glob: String,
+    },
+    ExtraInput {
+        input: String,
+    },
+    Whole {
+        glob: String,
+        input: String,
+        matched: bool,
+    },
+    Negated {
+        matched: bool,
+    },
+    Dotfile {
+        input: String,
+    },
+    Case {
+        input: String,
+    },
+}
+
+impl fmt::Display for ExplainStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExplainStep::Literal => f.write_str("input is identical to the pattern"),
+            ExplainStep::Basename { basename } => {
+                write!(f, "matching basename `{basename}` because `match_base` is set")
+            }
+            ExplainStep::Matched { index, glob, input } if input.is_empty() => {
+                write!(f, "segment {} {} matched nothing", index + 1, Quoted(glob))
+            }
+            ExplainStep::Matched { index, glob, input } => write!(
+                f,
+                "segment {} {} matched {}",
+                index + 1,
+                Quoted(glob),
+                Quoted(input)
+            ),
+            ExplainStep::Mismatch { index, glob, input } => write!(
+                f,
+                "segment {} {} did not match {}",
+                index + 1,
+                Quoted(glob),
+                Quoted(input)
+            ),
+            ExplainStep::MissingInput { index, glob } => write!(
+                f,
+                "segment {} {} had no input left to match",
+                index + 1,
+                Quoted(glob)
+            ),
+            ExplainStep::ExtraInput { input } => {
+                write!(f, "{} was left over after the last segment", Quoted(input))
+            }
+            ExplainStep::Whole {
+                glob,
+                input,
+                matched: true,
+            } => write!(f, "`{glob}` matched {}", Quoted(input)),
+            ExplainStep::Whole { glob, input, .. } => {
+                write!(f, "`{glob}` did not match {}", Quoted(input))
+            }
+            ExplainStep::Negated { matched: true } => f.write_str(
+                "negated by leading `!`: the rest of the pattern matched, so the input is rejected",
+            ),
+            ExplainStep::Negated { matched: false } => f.write_str(
+                "negated by leading `!`: the rest of the pattern did not match, so the input is accepted",
+            ),
+            ExplainStep::Dotfile { input } => {
+                write!(f, "dotfile `{input}` excluded because `dot` is false")
+            }
+            ExplainStep::Case { input } => {
+                write!(f, "`{input}` differs only in case; set `nocase` to match")
+            }
+        }
+    }
+}
+
+// Leading and trailing slashes produce empty segments, which would otherwise
+// render as a confusing pair of backticks.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("(empty)")
+        } else {
+            write!(f, "`{}`", self.0)
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verb = if self.is_match {
+            "matches"
+        } else {
+            "does not match"
+        };
+        write!(f, "`{}` {verb} `{}`", self.input, self.glob)?;
+        for step in &self.steps {
+            write!(f, "\n  - {step}")?;
+        }
+        Ok(())
+    }
+}
+
+enum Segment {
+    Empty,
+    Globstar(Matcher),
+    Glob(Matcher),
+}
+
+impl Segment {
+    fn is_match(&self, input: &str) -> Result<bool, MatchError> {
+        match self {
+            Segment::Empty => Ok(input.is_empty()),
+            Segment::Globstar(matcher) | Segment::Glob(matcher) => matcher.is_match(input),
+        }
+    }
+}
+
+// Explanations replay the pattern one path segment at a time, letting `**`
+// consume any number of segments, and report the furthest point the replay
+// reached. The replay is only a model of the compiled regex: whenever its
+// verdict disagrees with `is_match`, or the pattern has a slash inside braces
+// or an extglob, the explanation falls back to a single whole-pattern step.
+//
+// The segment and hint matchers are compiled on first use and kept, so one
+// `Explainer` can explain many inputs against the same pattern.
+pub struct Explainer<'a> {
+    matcher: &'a Matcher,
+    replayer: OnceCell<Option<Replayer<'a>>>,
+    dotted: OnceCell<Matcher>,
+    nocase: OnceCell<Matcher>,
+}
+
+impl<'a> Explainer<'a> {
+    pub(crate) fn new(matcher: &'a Matcher) -> Self {
+        Self {
+            matcher,
+            replayer: OnceCell::new(),
+            dotted: OnceCell::new(),
+            nocase: OnceCell::new(),
+        }
+    }
+
+    pub fn explain(&self, input: &str) -> Result<Explanation, MatchError> {
+        let glob = self.matcher.glob();
+        let options = self.matcher.options();
+        let is_match = self.matcher.is_match(input)?;
+        let mut steps = Vec::new();
+
+        if input.is_empty() || input == glob {
+            steps.push(if input.is_empty() {
+                ExplainStep::Whole {
+                    glob: glob.to_string(),
+                    input: String::new(),
+                    matched: false,
+                }
+            } else {
+                ExplainStep::Literal
+            });
+            return Ok(explanation(glob, input, is_match, steps));
+        }
+
+        let subject = if options.match_base || options.basename {
+            let (start, end) = basename_range(input, options.windows);
+            steps.push(ExplainStep::Basename {
+                basename: input[start..end].to_string(),
+            });
+            &input[start..end]
+        } else {
+            input
+        };
+
+        let (positive, negated) = split_negation(glob, options);
+        let matched = is_match != negated;
+        let replayer = self
+            .replayer
+            .get_or_init(|| Replayer::new(positive, options));
+        let replayed = match replayer {
+            Some(replayer) => Some(replayer.replay(subject, options)?),
+            None => None,
+        };
+        match replayed {
+            Some(replayed) if replayed.matched == matched => steps.extend(replayed.steps),
+            _ => steps.push(ExplainStep::Whole {
+                glob: positive.to_string(),
+                input: subject.to_string(),
+                matched,
+            }),
+        }
+        if negated {
+            steps.push(ExplainStep::Negated { matched });
+        }
+
+        if !is_match {
+            steps.extend(self.hints(input, subject)?);
+        }
+
+        Ok(explanation(glob, input, is_match, steps))
+    }
+
+    // Options that would have made the input match are reported as hints.
+    // They are checked against the full matcher, so a hint is never a guess.
+    fn hints(&self, input: &str, subject: &str) -> Result<Vec<ExplainStep>, MatchError> {
+        let options = self.matcher.options();
+        let mut hints = Vec::new();
+
+        if !options.dot {
+            let dotfile = split_path(subject, options.windows)
+                .into_iter()
+                .find(|segment| segment.starts_with('.'));
+            if let Some(dotfile) = dotfile {
+                let dotted = CompileOptions {
+                    dot: true,
+                    ..options.clone()
+                };
+                if self.hint_matcher(&self.dotted, &dotted)?.is_match(input)? {
+                    hints.push(ExplainStep::Dotfile {
+                        input: dotfile.to_string(),
+                    });
+                }
+            }
+        }
+
+        if !options.nocase && !options.flags.contains('i') {
+            let nocase = CompileOptions {
+                nocase: true,
+                ..options.clone()
+            };
+            if self.hint_matcher(&self.nocase, &nocase)?.is_match(input)? {
+                hints.push(ExplainStep::Case {
+                    input: subject.to_string(),
+                });
+            }
+        }
+
+        Ok(hints)
+    }
+
+    fn hint_matcher<'c>(
+        &self,
+        cell: &'c OnceCell<Matcher>,
+        options: &CompileOptions,
+    ) -> Result<&'c Matcher, MatchError> {
+        if let Some(matcher) = cell.get() {
+            return Ok(matcher);
+        }
+        let matcher = compile_matcher(self.matcher.glob(), options)?;
+        Ok(cell.get_or_init(|| matcher))
+    }
+}
+
+fn explanation(glob: &str, input: &str, is_match: bool, steps: Vec<ExplainStep>) -> Explanation {
+    Explanation {
+        glob: glob.to_string(),
+        input: input.to_string(),
+        is_match,
+        steps,
+    }
+}
+
+struct Replay {
+    matched: bool,
+    steps: Vec<ExplainStep>,
+}
+
+struct Replayer<'a> {
+    pattern: &'a str,
+    globs: Vec<&'a str>,
+    segments: Vec<Segment>,
+}
+
+impl<'a> Replayer<'a> {
+    fn new(pattern: &'a str, options: &CompileOptions) -> Option<Self> {
+        let globs = split_pattern(pattern, options)?;
+        let segment_options = CompileOptions {
+            match_base: false,
+            basename: false,
+            nonegate: true,
+            ..options.clone()
+        };
+        let mut segments = Vec::with_capacity(globs.len());
+        for glob in &globs {
+            let segment = if glob.is_empty() {
+                Segment::Empty
+            } else {
+                let matcher = compile_matcher(glob, &segment_options).ok()?;
+                if *glob == "**" && !options.noglobstar {
+                    Segment::Globstar(matcher)
+                } else {
+                    Segment::Glob(matcher)
+                }
+            };
+            segments.push(segment);
+        }
+
+        Some(Self {
+            pattern,
+            globs,
+            segments,
+        })
+    }
+
+    fn replay(&self, subject: &str, options: &CompileOptions) -> Result<Replay, MatchError> {
+        let (globs, segments) = (&self.globs, &self.segments);
+        let subject = if options.strict_slashes || self.pattern.ends_with('/') {
+            subject
+        } else {
+            match trim_trailing_separators(subject, options.windows) {
+                "" => subject,
+                trimmed => trimmed,
+            }
+        };
+        let inputs = split_path(subject, options.windows);
+
+        // `from[i][j]` records how the first `i` segments consumed exactly the
+        // first `j` inputs: the input index the last segment started at.
+        let (n, m) = (segments.len(), inputs.len());
+        let mut from = vec![vec![None; m + 1]; n + 1];
+        from[0][0] = Some(0);
+        for i in 0..n {
+            for j in 0..=m {
+                if from[i][j].is_none() {
+                    continue;
+                }
+                match &segments[i] {
+                    Segment::Globstar(matcher) => {
+                        let mut end = j;
+                        loop {
+                            if from[i + 1][end].is_none() {
+                                from[i + 1][end] = Some(j);
+                            }
+                            if end == m || !matcher.is_match(inputs[end])? {
+                                break;
+                            }
+                            end += 1;
+                        }
+                    }
+                    segment => {
+                        if j < m && segment.is_match(inputs[j])? && from[i + 1][j + 1].is_none() {
+                            from[i + 1][j + 1] = Some(j);
+                        }
+                    }
+                }
+            }
+        }
+
+        let (i, j) = if from[n][m].is_some() {
+            (n, m)
+        } else {
+            (0..=n)
+                .flat_map(|i| (0..=m).map(move |j| (i, j)))
+                .filter(|&(i, j)| from[i][j].is_some())
+                .max()
+                .unwrap_or((0, 0))
+        };
+
+        let mut steps = Vec::new();
+        let (mut si, mut sj) = (i, j);
+        while si > 0 {
+            let start = from[si][sj].unwrap_or(0);
+            steps.push(ExplainStep::Matched {
+                index: si - 1,
+                glob: globs[si - 1].to_string(),
+                input: inputs[start..sj].join("/"),
+            });
+            si -= 1;
+            sj = start;
+        }
+        steps.reverse();
+
+        let matched = i == n && j == m;
+        if !matched {
+            steps.push(if i == n {
+                ExplainStep::ExtraInput {
+                    input: inputs[j..].join("/"),
+                }
+            } else if j == m {
+                ExplainStep::MissingInput {
+                    index: i,
+                    glob: globs[i].to_string(),
+                }
+            } else {
+                ExplainStep::Mismatch {
+                    index: i,
+                    glob: globs[i].to_string(),
+                    input: inputs[j].to_string(),
+                }
+            });
+        }
+
+        Ok(Replay { matched, steps })
+    }
+}
+
+// Splits at slashes outside brackets. A slash inside braces or an extglob
+// spans segments, which the replay cannot model.
+fn split_pattern<'a>(pattern: &'a str, options: &CompileOptions) -> Option<Vec<&'a str>> {
+    let chars = pattern.char_indices().collect::<Vec<_>>();
+    let plain = pattern.chars().collect::<Vec<_>>();
+    let mut segments = Vec::new();
+    let mut start = 0usize;
+    let mut depth = 0usize;
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let (offset, ch) = chars[index];
+        match ch {
+            '\\' => {
+                index += 2;
+                continue;
+            }
+            '[' if !options.nobracket => {
+                if let Some((_, next)) = collect_bracket(&plain, index) {
+                    index = next;
+                    continue;
+                }
+            }
+            '{' | '(' => depth += 1,
+            '}' | ')' => depth = depth.saturating_sub(1),
+            '/' if depth > 0 => return None,
+            '/' => {
+                segments.push(&pattern[start..offset]);
+                start = offset + 1;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    segments.push(&pattern[start..]);
+    Some(segments)
+}
+
+fn split_path(input: &str, windows: bool) -> Vec<&str> {
+    input
+        .split(|ch| ch == '/' || (windows && ch == '\\'))
+        .collect()
+}
@@ -0,0 +1,344 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{compile_matcher, CompileOptions, MatchError, Matcher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitignoreMatch {
+    None,
+    Ignore,
+    Whitelist,
+}
+
+impl GitignoreMatch {
+    pub fn is_none(self) -> bool {
+        self == GitignoreMatch::None
+    }
+
+    pub fn is_ignore(self) -> bool {
+        self == GitignoreMatch::Ignore
+    }
+
+    pub fn is_whitelist(self) -> bool {
+        self == GitignoreMatch::Whitelist
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitignoreRule {
+    pub original: String,
+    pub glob: String,
+    pub negated: bool,
+    pub dir_only: bool,
+    pub anchored: bool,
+    matcher: Matcher,
+}
+
+impl GitignoreRule {
+    pub fn is_match(&self, path: &str, is_dir: bool) -> Result<bool, MatchError> {
+        if self.dir_only && !is_dir {
+            return Ok(false);
+        }
+
+        self.matcher.is_match(path)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GitignoreMatcher {
+    root: String,
+    rules: Vec<GitignoreRule>,
+}
+
+impl GitignoreMatcher {
+    pub fn new(root: &str) -> Self {
+        Self {
+            root: normalize_path(root).to_string(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn parse(root: &str, contents: &str) -> Result<Self, MatchError> {
+        let mut matcher = Self::new(root);
+        for line in contents.lines() {
+            matcher.add_line(line)?;
+        }
+        Ok(matcher)
+    }
+
+    pub fn add_line(&mut self, line: &str) -> Result<(), MatchError> {
+        if let Some(rule) = parse_rule(line)? {
+            self.rules.push(rule);
+        }
+        Ok(())
+    }
+
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn rules(&self) -> &[GitignoreRule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn matched(&self, path: &str, is_dir: bool) -> Result<GitignoreMatch, MatchError> {
+        let Some(relative) = self.relative(path) else {
+            return Ok(GitignoreMatch::None);
+        };
+
+        self.matched_relative(relative, is_dir)
+    }
+
+    pub fn matched_path_or_any_parents(
+        &self,
+        path: &str,
+        is_dir: bool,
+    ) -> Result<GitignoreMatch, MatchError> {
+        let Some(relative) = self.relative(path) else {
+            return Ok(GitignoreMatch::None);
+        };
+
+        for (index, _) in relative.match_indices('/') {
+            if self.matched_relative(&relative[..index], true)? == GitignoreMatch::Ignore {
+                return Ok(GitignoreMatch::Ignore);
+            }
+        }
+
+        self.matched_relative(relative, is_dir)
+    }
+
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> Result<bool, MatchError> {
+        Ok(self.matched_path_or_any_parents(path, is_dir)?.is_ignore())
+    }
+
+    fn matched_relative(&self, path: &str, is_dir: bool) -> Result<GitignoreMatch, MatchError> {
+        for rule in self.rules.iter().rev() {
+            if rule.is_match(path, is_dir)? {
+                return Ok(if rule.negated {
+                    GitignoreMatch::Whitelist
+                } else {
+                    GitignoreMatch::Ignore
+                });
+            }
+        }
+
+        Ok(GitignoreMatch::None)
+    }
+
+    fn relative<'a>(&self, path: &'a str) -> Option<&'a str> {
+        let path = normalize_path(path);
+        if self.root.is_empty() {
+            return (!path.is_empty()).then_some(path);
+        }
+
+        path.strip_prefix(self.root.as_str())?
+            .strip_prefix('/')
+            .filter(|rest| !rest.is_empty())
+    }
+}
+
+pub(crate) fn normalize_path(path: &str) -> &str {
+    let mut path = path;
+    while let Some(stripped) = path.strip_prefix("./") {
+        path = stripped;
+    }
+    if path == "." {
+        return "";
+    }
+    path.trim_end_matches('/')
+}
+
+fn gitignore_options() -> CompileOptions {
+    CompileOptions {
+        dot: true,
+        nobrace: true,
+        noextglob: true,
+        nonegate: true,
+        strict_slashes: true,
+        ..CompileOptions::default()
+    }
+}
+
+fn parse_rule(line: &str) -> Result<Option<GitignoreRule>, MatchError> {
+    if line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let mut pattern = trim_trailing_spaces(line);
+    let negated = pattern.starts_with('!');
+    if negated {
+        pattern = &pattern[1..];
+    }
+
+    let dir_only = pattern.ends_with('/');
+    let pattern = pattern.trim_end_matches('/');
+    if pattern.is_empty() {
+        return Ok(None);
+    }
+
+    let anchored = pattern.contains('/');
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    let Some(mut glob) = translate(pattern) else {
+        return Ok(None);
+    };
+
+    if !anchored {
+        glob.insert_str(0, "**/");
+    }
+    if glob.ends_with("/**") {
+        glob.push_str("/*");
+    }
+
+    let matcher = compile_matcher(&glob, &gitignore_options())?;
+    Ok(Some(GitignoreRule {
+        original: line.to_string(),
+        glob,
+        negated,
+        dir_only,
+        anchored,
+        matcher,
+    }))
+}
+
+fn trim_trailing_spaces(line: &str) -> &str {
+    let mut end = 0usize;
+    let mut escaped = false;
+
+    for (index, ch) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            end = index + ch.len_utf8();
+        } else if ch == '\\' {
+            escaped = true;
+            end = index + 1;
+        } else if ch != ' ' {
+            end = index + ch.len_utf8();
+        }
+    }
+
+    &line[..end]
+}
+
+// Rewrites wildmatch syntax into an equivalent picomatch glob. Only `*`, `?`,
+// `[...]` and `\` are special to git, so every other glob metacharacter is
+// escaped. Returns `None` for patterns git can never match (an unclosed `[`).
+fn translate(pattern: &str) -> Option<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut output = String::with_capacity(pattern.len() + 8);
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        match ch {
+            '\\' => {
+                let next = *chars.get(index + 1)?;
+                push_escaped(&mut output, next);
+                index += 2;
+            }
+            '[' => {
+                let end = bracket_end(&chars, index)?;
+                output.push_str(&translate_bracket(&chars[index + 1..end]));
+                index = end + 1;
+            }
+            '*' | '?' | '/' => {
+                output.push(ch);
+                index += 1;
+            }
+            _ => {
+                push_escaped(&mut output, ch);
+                index += 1;
+            }
+        }
+    }
+
+    Some(output)
+}
+
+fn push_escaped(output: &mut String, ch: char) {
+    if ch.is_alphanumeric()
+        || matches!(
+            ch,
+            ' ' | '-' | '_' | '#' | '%' | '~' | '=' | ':' | ';' | '/'
+        )
+    {
+        output.push(ch);
+    } else {
+        output.push('\\');
+        output.push(ch);
+    }
+}
+
+fn bracket_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    if matches!(chars.get(index), Some('!' | '^')) {
+        index += 1;
+    }
+    if chars.get(index) == Some(&']') {
+        index += 1;
+    }
+
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 2,
+            '[' if chars.get(index + 1) == Some(&':') => {
+                let close = (index + 2..chars.len().saturating_sub(1))
+                    .find(|&end| chars[end] == ':' && chars[end + 1] == ']')?;
+                index = close + 2;
+            }
+            ']' => return Some(index),
+            _ => index += 1,
+        }
+    }
+
+    None
+}
+
+// `compile_bracket` also lets a plain class like `[abc]` match the literal
+// text `[abc]`. Git never does that, so a class without range or escape
+// syntax gets its first member rewritten as the equivalent range `a-a`.
+fn translate_bracket(inner: &[char]) -> String {
+    let mut output = String::from("[");
+    let mut body = inner;
+
+    if let Some((&first, rest)) = body.split_first() {
+        if matches!(first, '!' | '^') {
+            output.push('!');
+            body = rest;
+        }
+    }
+
+    let plain = !body.iter().any(|ch| {
+        matches!(
+            ch,
+            '-' | '\\'
+                | '['
+                | ']'
+                | '.'
+                | '^'
+                | '$'
+                | '*'
+                | '+'
+                | '?'
+                | '('
+                | ')'
+                | '{'
+                | '}'
+                | '|'
+        )
+    });
+    if let (true, Some((&first, rest))) = (plain, body.split_first()) {
+        output.push(first);
+        output.push('-');
+        output.push(first);
+        output.extend(rest);
+    } else {
+        output.extend(body);
+    }
+
+    output.push(']');
+    output
+}
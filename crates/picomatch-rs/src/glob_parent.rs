@@ -0,0 +1,133 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::is_glob::{is_glob, IsGlobOptions};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
+pub struct GlobParentOptions {
+    pub flip_backslashes: bool,
+}
+
+impl Default for GlobParentOptions {
+    fn default() -> Self {
+        Self {
+            flip_backslashes: true,
+        }
+    }
+}
+
+// The directory part of a glob before its first glob segment, with
+// glob-parent's semantics: `src/**/*.js` gives `src`, `src/lib/` keeps its
+// trailing directory and gives `src/lib`, and `.` stands for no directory.
+// Escaped glob characters are unescaped in the result. Like glob-parent,
+// backslashes are only flipped to slashes on Windows, and only when the glob
+// has no slash.
+pub fn glob_parent(input: &str, options: &GlobParentOptions) -> String {
+    let mut path = if options.flip_backslashes && cfg!(windows) && !input.contains('/') {
+        input.replace('\\', "/")
+    } else {
+        input.to_string()
+    };
+
+    // `{a,b/c}` and `[a/b]` end in an enclosure holding a separator, which
+    // must not be taken for the last path segment.
+    if is_enclosure(&path) {
+        path.push('/');
+    }
+    // Keeps a trailing separator from being stripped as the last segment.
+    path.push('a');
+
+    loop {
+        path = posix_dirname(&path).to_string();
+        if !is_globby(&path) {
+            break;
+        }
+    }
+    unescape_glob_chars(&path)
+}
+
+fn is_enclosure(path: &str) -> bool {
+    let start = match path.chars().last() {
+        Some('}') => '{',
+        Some(']') => '[',
+        _ => return false,
+    };
+    match path.find(start) {
+        Some(found) => path[found + 1..path.len() - 1].contains('/'),
+        None => false,
+    }
+}
+
+fn is_globby(path: &str) -> bool {
+    let chars = path.chars().collect::<Vec<_>>();
+    // An unclosed parenthesis in the last part, as in `/\([^()]+$/`.
+    let unclosed_paren = chars
+        .iter()
+        .rposition(|&ch| ch == '(' || ch == ')')
+        .is_some_and(|index| chars[index] == '(' && index + 1 < chars.len());
+    if unclosed_paren || matches!(chars.first(), Some('{' | '[')) {
+        return true;
+    }
+    if chars
+        .windows(2)
+        .any(|pair| pair[0] != '\\' && matches!(pair[1], '{' | '['))
+    {
+        return true;
+    }
+    is_glob(path, &IsGlobOptions::default())
+}
+
+// Node's `path.posix.dirname`.
+fn posix_dirname(path: &str) -> &str {
+    if path.is_empty() {
+        return ".";
+    }
+    let has_root = path.starts_with('/');
+    let bytes = path.as_bytes();
+    let mut end = None;
+    let mut matched_slash = true;
+    for index in (1..bytes.len()).rev() {
+        if bytes[index] == b'/' {
+            if !matched_slash {
+                end = Some(index);
+                break;
+            }
+        } else {
+            matched_slash = false;
+        }
+    }
+
+    match end {
+        None if has_root => "/",
+        None => ".",
+        Some(1) if has_root => "//",
+        Some(end) => &path[..end],
+    }
+}
+
+// Drops the backslash before any of `!*?|[](){}`.
+fn unescape_glob_chars(path: &str) -> String {
+    let mut output = String::with_capacity(path.len());
+    let mut chars = path.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            if let Some(&next) = chars.peek() {
+                if matches!(
+                    next,
+                    '!' | '*' | '?' | '|' | '[' | ']' | '(' | ')' | '{' | '}'
+                ) {
+                    output.push(next);
+                    chars.next();
+                    continue;
+                }
+            }
+        }
+        output.push(ch);
+    }
+    output
+}
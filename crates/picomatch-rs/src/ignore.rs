@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::gitignore::normalize_path;
+use crate::utils::decode_path_bytes;
+use crate::{GitignoreMatch, GitignoreMatcher, MatchError};
+
+pub const DEFAULT_IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+
+#[derive(Debug)]
+pub enum IgnoreError {
+    Io { path: PathBuf, error: io::Error },
+    Pattern { path: PathBuf, error: MatchError },
+}
+
+impl fmt::Display for IgnoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IgnoreError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            IgnoreError::Pattern { path, error } => write!(f, "{}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for IgnoreError {}
+
+#[derive(Debug, Clone)]
+pub struct IgnoreTree {
+    root: PathBuf,
+    file_names: Vec<String>,
+    global: Vec<GitignoreMatcher>,
+    dirs: HashMap<String, Vec<GitignoreMatcher>>,
+}
+
+impl IgnoreTree {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self::with_file_names(root, DEFAULT_IGNORE_FILES.iter().copied())
+    }
+
+    // Files later in `file_names` take precedence over earlier ones in the same
+    // directory, and files in deeper directories take precedence over both.
+    pub fn with_file_names<I, S>(root: impl Into<PathBuf>, file_names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            root: root.into(),
+            file_names: file_names.into_iter().map(Into::into).collect(),
+            global: Vec::new(),
+            dirs: HashMap::new(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn file_names(&self) -> &[String] {
+        &self.file_names
+    }
+
+    pub fn add_global(&mut self, matcher: GitignoreMatcher) {
+        self.global.push(matcher);
+    }
+
+    pub fn load_dir(&mut self, dir: &str) -> Result<(), IgnoreError> {
+        let dir = normalize_path(dir);
+        let path = self.root.join(dir);
+        self.load_dir_at(dir, &path)
+    }
+
+    // Loads the ignore files in `path`, recording them under the
+    // already-normalized `dir`.
+    fn load_dir_at(&mut self, dir: &str, path: &Path) -> Result<(), IgnoreError> {
+        if self.dirs.contains_key(dir) {
+            return Ok(());
+        }
+
+        let mut matchers = Vec::new();
+        for name in &self.file_names {
+            let path = path.join(name);
+            let bytes = match fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(IgnoreError::Io { path, error }),
+            };
+            let contents = String::from_utf8_lossy(&bytes);
+            let matcher = GitignoreMatcher::parse(dir, &contents)
+                .map_err(|error| IgnoreError::Pattern { path, error })?;
+            if !matcher.is_empty() {
+                matchers.push(matcher);
+            }
+        }
+
+        self.dirs.insert(dir.to_string(), matchers);
+        Ok(())
+    }
+
+    pub fn matched(&mut self, path: &str, is_dir: bool) -> Result<GitignoreMatch, IgnoreError> {
+        let path = normalize_path(path);
+        if path.is_empty() {
+            return Ok(GitignoreMatch::None);
+        }
+
+        self.load_dir("")?;
+        for (index, _) in path.match_indices('/') {
+            let parent = &path[..index];
+            if self.matched_entry(parent, true)? == GitignoreMatch::Ignore {
+                return Ok(GitignoreMatch::Ignore);
+            }
+            self.load_dir(parent)?;
+        }
+
+        self.matched_entry(path, is_dir)
+    }
+
+    pub fn is_ignored(&mut self, path: &str, is_dir: bool) -> Result<bool, IgnoreError> {
+        Ok(self.matched(path, is_dir)?.is_ignore())
+    }
+
+    // Files that are not ignored, relative to the root and joined with `/`.
+    // Names that are not valid UTF-8 are kept as they are on disk and matched
+    // against the ignore rules as `Matcher::is_match_os_str` would.
+    pub fn walk(&mut self) -> Result<Vec<PathBuf>, IgnoreError> {
+        let mut files = Vec::new();
+        let mut pending = vec![(String::new(), OsString::new())];
+
+        while let Some((dir, dir_path)) = pending.pop() {
+            let path = self.root.join(&dir_path);
+            self.load_dir_at(&dir, &path)?;
+            let mut entries = fs::read_dir(&path)
+                .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+                .map_err(|error| IgnoreError::Io { path, error })?;
+            entries.sort_by_key(|entry| entry.file_name());
+
+            let mut subdirs = Vec::new();
+            for entry in entries {
+                let file_name = entry.file_name();
+                let name = decode_path_bytes(file_name.as_encoded_bytes());
+                if name == ".git" {
+                    continue;
+                }
+
+                let (relative, mut relative_path) = if dir.is_empty() {
+                    (name.into_owned(), OsString::new())
+                } else {
+                    let mut relative_path = dir_path.clone();
+                    relative_path.push("/");
+                    (format!("{dir}/{name}"), relative_path)
+                };
+                relative_path.push(&file_name);
+                let is_dir = entry
+                    .file_type()
+                    .map_err(|error| IgnoreError::Io {
+                        path: entry.path(),
+                        error,
+                    })?
+                    .is_dir();
+
+                if self.matched_entry(&relative, is_dir)? == GitignoreMatch::Ignore {
+                    continue;
+                }
+
+                if is_dir {
+                    subdirs.push((relative, relative_path));
+                } else {
+                    files.push(PathBuf::from(relative_path));
+                }
+            }
+
+            pending.extend(subdirs.into_iter().rev());
+        }
+
+        Ok(files)
+    }
+
+    // Assumes every ancestor directory of `path` has been loaded and is not
+    // itself ignored.
+    fn matched_entry(&self, path: &str, is_dir: bool) -> Result<GitignoreMatch, IgnoreError> {
+        let mut dir = parent(path);
+        loop {
+            if let Some(matchers) = self.dirs.get(dir) {
+                for matcher in matchers.iter().rev() {
+                    let matched = matcher
+                        .matched(path, is_dir)
+                        .map_err(|error| self.pattern_error(dir, error))?;
+                    if !matched.is_none() {
+                        return Ok(matched);
+                    }
+                }
+            }
+
+            if dir.is_empty() {
+                break;
+            }
+            dir = parent(dir);
+        }
+
+        for matcher in self.global.iter().rev() {
+            let matched = matcher
+                .matched(path, is_dir)
+                .map_err(|error| self.pattern_error("", error))?;
+            if !matched.is_none() {
+                return Ok(matched);
+            }
+        }
+
+        Ok(GitignoreMatch::None)
+    }
+
+    fn pattern_error(&self, dir: &str, error: MatchError) -> IgnoreError {
+        IgnoreError::Pattern {
+            path: self.root.join(dir),
+            error,
+        }
+    }
+}
+
+fn parent(path: &str) -> &str {
+    path.rfind('/').map_or("", |index| &path[..index])
+}
@@ -0,0 +1,252 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{compile_matcher, escape_glob, CompileOptions, MatchError};
+
+// Basenames listed in a brace before a group is split further.
+const MAX_BRACE_NAMES: usize = 8;
+
+// Suggests a small set of globs that together match every `include` path and
+// none of the `exclude` paths, such as `packages/*/src/**/*.ts` for a list of
+// sources. Paths are generalized as far as the excludes allow, and groups
+// that cannot share a glob are split by extension and then by directory, down
+// to literal paths. Every glob is checked with `compile_matcher`. Returns
+// `None` when no glob set separates the lists, because a path is in both.
+pub fn infer_globs(
+    include: &[&str],
+    exclude: &[&str],
+    options: &CompileOptions,
+) -> Result<Option<Vec<String>>, MatchError> {
+    let paths = include
+        .iter()
+        .filter(|path| !path.is_empty())
+        .map(|path| Entry::new(path, options))
+        .collect::<Vec<_>>();
+    if paths.is_empty() {
+        return Ok(Some(Vec::new()));
+    }
+
+    let mut globs = Vec::new();
+    if !cover(&paths, 0, exclude, options, &mut globs)? {
+        return Ok(None);
+    }
+    Ok(Some(globs))
+}
+
+#[derive(Clone)]
+struct Entry<'a> {
+    input: &'a str,
+    dirs: Vec<&'a str>,
+    base: &'a str,
+}
+
+impl<'a> Entry<'a> {
+    fn new(input: &'a str, options: &CompileOptions) -> Self {
+        let is_separator = |ch: char| ch == '/' || (options.windows && ch == '\\');
+        let mut dirs = input
+            .trim_end_matches(is_separator)
+            .split(is_separator)
+            .collect::<Vec<_>>();
+        let base = dirs.pop().unwrap_or_default();
+        Self { input, dirs, base }
+    }
+
+    // The extension, including compound ones such as `.test.ts`.
+    fn extension(&self) -> &'a str {
+        match self.base.char_indices().skip(1).find(|&(_, ch)| ch == '.') {
+            Some((index, _)) => &self.base[index..],
+            None => "",
+        }
+    }
+}
+
+// Finds one glob for `paths`, or splits them by extension, then by the
+// directory at `depth`, and covers each part on its own.
+fn cover(
+    paths: &[Entry<'_>],
+    depth: usize,
+    exclude: &[&str],
+    options: &CompileOptions,
+    globs: &mut Vec<String>,
+) -> Result<bool, MatchError> {
+    for glob in candidates(paths) {
+        if separates(&glob, paths, exclude, options)? {
+            globs.push(glob);
+            return Ok(true);
+        }
+    }
+    if paths.len() == 1 {
+        return Ok(false);
+    }
+
+    let by_extension = group(paths, |path| path.extension());
+    if by_extension.len() > 1 {
+        for part in by_extension {
+            if !cover(&part, depth, exclude, options, globs)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    let mut depth = depth;
+    while paths.iter().any(|path| depth < path.dirs.len()) {
+        let by_dir = group(paths, |path| {
+            path.dirs.get(depth).copied().unwrap_or_default()
+        });
+        if by_dir.len() > 1 {
+            for part in by_dir {
+                if !cover(&part, depth + 1, exclude, options, globs)? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+        depth += 1;
+    }
+
+    // Same directory and extension: only the basenames tell them apart.
+    for path in paths {
+        if !cover(core::slice::from_ref(path), depth, exclude, options, globs)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn group<'a>(paths: &[Entry<'a>], key: impl Fn(&Entry<'a>) -> &'a str) -> Vec<Vec<Entry<'a>>> {
+    let mut groups = BTreeMap::<&str, Vec<Entry<'a>>>::new();
+    for path in paths {
+        groups.entry(key(path)).or_default().push(path.clone());
+    }
+    groups.into_values().collect()
+}
+
+fn separates(
+    glob: &str,
+    paths: &[Entry<'_>],
+    exclude: &[&str],
+    options: &CompileOptions,
+) -> Result<bool, MatchError> {
+    let matcher = compile_matcher(glob, options)?;
+    for path in paths {
+        if !matcher.is_match(path.input)? {
+            return Ok(false);
+        }
+    }
+    for path in exclude {
+        if matcher.is_match(path)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// Globs matching every path, most specific first: directories aligned
+// segment by segment, then a common prefix and suffix around `**`, then a
+// common prefix alone. Basenames are kept literal, then reduced to their
+// shared extension, then to `*`, with a brace of the names as a last resort.
+fn candidates<'a>(paths: &[Entry<'a>]) -> Vec<String> {
+    let min_depth = paths.iter().map(|path| path.dirs.len()).min().unwrap_or(0);
+    let same_depth = paths.iter().all(|path| path.dirs.len() == min_depth);
+    let column = |index: usize, from_end: bool| {
+        let segment = |path: &Entry<'a>| -> &'a str {
+            if from_end {
+                path.dirs[path.dirs.len() - 1 - index]
+            } else {
+                path.dirs[index]
+            }
+        };
+        let first = segment(&paths[0]);
+        paths
+            .iter()
+            .all(|path| segment(path) == first)
+            .then_some(first)
+    };
+
+    let aligned = (0..min_depth)
+        .map(|index| column(index, false).map_or("*".to_string(), escape_glob))
+        .collect::<Vec<_>>();
+    let prefix = (0..min_depth)
+        .map_while(|index| column(index, false))
+        .map(escape_glob)
+        .collect::<Vec<_>>();
+    let suffix = (0..min_depth - prefix.len())
+        .map_while(|index| column(index, true))
+        .map(escape_glob)
+        .collect::<Vec<_>>();
+
+    let mut dir_globs = Vec::new();
+    if same_depth {
+        dir_globs.push(aligned);
+    } else {
+        if !suffix.is_empty() {
+            let mut dirs = prefix.clone();
+            dirs.push("**".to_string());
+            dirs.extend(suffix.into_iter().rev());
+            dir_globs.push(dirs);
+        }
+        let mut dirs = aligned;
+        dirs.push("**".to_string());
+        dir_globs.push(dirs);
+    }
+    let mut dirs = prefix;
+    dirs.push("**".to_string());
+    dir_globs.push(dirs);
+
+    let base_globs = base_globs(paths);
+    let mut globs = Vec::new();
+    for dirs in &dir_globs {
+        for base in &base_globs {
+            let glob = dirs
+                .iter()
+                .chain(core::iter::once(base))
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("/");
+            if !globs.contains(&glob) {
+                globs.push(glob);
+            }
+        }
+    }
+    globs
+}
+
+fn base_globs(paths: &[Entry<'_>]) -> Vec<String> {
+    let first = paths[0].base;
+    if paths.iter().all(|path| path.base == first) {
+        return vec![escape_glob(first)];
+    }
+
+    let mut globs = Vec::new();
+    let mut extension = paths[0].extension();
+    while !extension.is_empty() {
+        if paths
+            .iter()
+            .all(|path| path.extension().ends_with(extension))
+        {
+            globs.push(format!("*{}", escape_glob(extension)));
+        }
+        extension = match extension[1..].find('.') {
+            Some(index) => &extension[index + 1..],
+            None => "",
+        };
+    }
+    globs.push("*".to_string());
+
+    let mut names = paths.iter().map(|path| path.base).collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    if names.len() <= MAX_BRACE_NAMES {
+        // Commas would split the brace.
+        let names = names
+            .into_iter()
+            .map(|name| escape_glob(name).replace(',', "\\,"))
+            .collect::<Vec<_>>();
+        globs.push(format!("{{{}}}", names.join(",")));
+    }
+    globs
+}
@@ -0,0 +1,278 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::CompileOptions;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
+pub struct IsGlobOptions {
+    pub strict: bool,
+}
+
+impl Default for IsGlobOptions {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
+// Whether `input` contains an extglob such as `+(a|b)` outside an escape,
+// with is-extglob's semantics.
+pub fn is_extglob(input: &str) -> bool {
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let next = chars.get(index + 1).copied();
+        if ch == '\\' && next.is_some_and(|next| next != '\n') {
+            index += 2;
+            continue;
+        }
+        if matches!(ch, '@' | '?' | '!' | '+' | '*')
+            && next == Some('(')
+            && chars[index + 2..]
+                .iter()
+                .take_while(|&&ch| ch != '\n')
+                .any(|&ch| ch == ')')
+        {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+// Whether `input` looks like a glob, with is-glob's semantics. The default
+// strict mode only accepts complete constructs, such as a closed bracket or
+// an alternation inside parentheses; `strict: false` accepts any special
+// character that is not escaped.
+pub fn is_glob(input: &str, options: &IsGlobOptions) -> bool {
+    if input.is_empty() {
+        return false;
+    }
+    if is_extglob(input) {
+        return true;
+    }
+
+    let chars = input.chars().collect::<Vec<_>>();
+    if options.strict {
+        strict_check(&chars)
+    } else {
+        relaxed_check(&chars)
+    }
+}
+
+// Whether `pattern` needs matching rather than a plain string comparison,
+// with fast-glob's semantics: `nocase`, `noextglob` and `nobrace` stand in
+// for its `caseSensitiveMatch`, `extglob` and `braceExpansion` options. Any
+// backslash makes a pattern dynamic.
+pub fn is_dynamic_pattern(pattern: &str, options: &CompileOptions) -> bool {
+    if pattern.is_empty() {
+        return false;
+    }
+    if options.nocase || pattern.contains('\\') {
+        return true;
+    }
+
+    let chars = pattern.chars().collect::<Vec<_>>();
+    if chars[0] == '!' || chars.iter().any(|&ch| ch == '*' || ch == '?') {
+        return true;
+    }
+    if has_character_class(&chars) || has_regex_group(&chars) {
+        return true;
+    }
+    if !options.noextglob && has_extglob(&chars) {
+        return true;
+    }
+    !options.nobrace && has_brace_expansion(pattern)
+}
+
+fn strict_check(chars: &[char]) -> bool {
+    if chars[0] == '!' {
+        return true;
+    }
+
+    let at = |index: usize| chars.get(index).copied();
+    // Positions as in the JavaScript original, where `-1` means "not found"
+    // and `-2` "not searched yet".
+    let find = |ch: char, from: usize| -> isize {
+        chars
+            .iter()
+            .skip(from)
+            .position(|&current| current == ch)
+            .map_or(-1, |offset| (from + offset) as isize)
+    };
+    let mut pipe_index = -2isize;
+    let mut close_square_index = -2isize;
+    let mut close_curly_index = -2isize;
+    let mut close_paren_index = -2isize;
+    let mut back_slash_index = -2isize;
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let position = index as isize;
+        let unescaped = |close: isize, back_slash: isize| back_slash == -1 || back_slash > close;
+
+        if chars[index] == '*' {
+            return true;
+        }
+        if at(index + 1) == Some('?') && matches!(chars[index], ']' | '.' | '+' | ')') {
+            return true;
+        }
+        if close_square_index != -1 && chars[index] == '[' && at(index + 1) != Some(']') {
+            if close_square_index < position {
+                close_square_index = find(']', index);
+            }
+            if close_square_index > position {
+                if unescaped(close_square_index, back_slash_index) {
+                    return true;
+                }
+                back_slash_index = find('\\', index);
+                if unescaped(close_square_index, back_slash_index) {
+                    return true;
+                }
+            }
+        }
+        if close_curly_index != -1 && chars[index] == '{' && at(index + 1) != Some('}') {
+            close_curly_index = find('}', index);
+            if close_curly_index > position {
+                back_slash_index = find('\\', index);
+                if unescaped(close_curly_index, back_slash_index) {
+                    return true;
+                }
+            }
+        }
+        if close_paren_index != -1
+            && chars[index] == '('
+            && at(index + 1) == Some('?')
+            && matches!(at(index + 2), Some(':' | '!' | '='))
+            && at(index + 3) != Some(')')
+        {
+            close_paren_index = find(')', index);
+            if close_paren_index > position {
+                back_slash_index = find('\\', index);
+                if unescaped(close_paren_index, back_slash_index) {
+                    return true;
+                }
+            }
+        }
+        if pipe_index != -1 && chars[index] == '(' && at(index + 1) != Some('|') {
+            if pipe_index < position {
+                pipe_index = find('|', index);
+            }
+            if pipe_index != -1 && at(pipe_index as usize + 1) != Some(')') {
+                close_paren_index = find(')', pipe_index as usize);
+                if close_paren_index > pipe_index {
+                    back_slash_index = find('\\', pipe_index as usize);
+                    if unescaped(close_paren_index, back_slash_index) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        if chars[index] == '\\' {
+            index = skip_escape(chars, index);
+            if at(index) == Some('!') {
+                return true;
+            }
+        } else {
+            index += 1;
+        }
+    }
+    false
+}
+
+fn relaxed_check(chars: &[char]) -> bool {
+    if chars[0] == '!' {
+        return true;
+    }
+
+    let mut index = 0usize;
+    while index < chars.len() {
+        if matches!(chars[index], '*' | '?' | '{' | '}' | '(' | ')' | '[' | ']') {
+            return true;
+        }
+        if chars[index] == '\\' {
+            index = skip_escape(chars, index);
+            if chars.get(index) == Some(&'!') {
+                return true;
+            }
+        } else {
+            index += 1;
+        }
+    }
+    false
+}
+
+// Steps over the backslash at `index` and the character it escapes. An
+// escaped opening bracket, brace or parenthesis also skips to just past its
+// closing counterpart, so `\\[a]` is not a glob.
+fn skip_escape(chars: &[char], index: usize) -> usize {
+    let close = match chars.get(index + 1) {
+        Some('{') => '}',
+        Some('(') => ')',
+        Some('[') => ']',
+        _ => return index + 2,
+    };
+    match chars.iter().skip(index + 2).position(|&ch| ch == close) {
+        Some(offset) => index + 2 + offset + 1,
+        None => index + 2,
+    }
+}
+
+// `[...]` with no other `[` inside, as in `/\[[^[]*]/`.
+fn has_character_class(chars: &[char]) -> bool {
+    chars
+        .iter()
+        .position(|&ch| ch == '[')
+        .is_some_and(|open| chars[open..].contains(&']'))
+}
+
+// `(a|b)` not preceded by an extglob character, as in
+// `/(?:^|[^!*+?@])\([^(]*\|[^|]*\)/`.
+fn has_regex_group(chars: &[char]) -> bool {
+    (0..chars.len()).any(|open| {
+        chars[open] == '('
+            && (open == 0 || !matches!(chars[open - 1], '!' | '*' | '+' | '?' | '@'))
+            && chars[open + 1..]
+                .iter()
+                .take_while(|&&ch| ch != '(')
+                .enumerate()
+                .any(|(offset, &ch)| {
+                    ch == '|' && closes_before(&chars[open + 2 + offset..], ')', '|')
+                })
+    })
+}
+
+// `+(...)` and friends, as in `/[!*+?@]\([^(]*\)/`.
+fn has_extglob(chars: &[char]) -> bool {
+    chars.windows(2).enumerate().any(|(index, pair)| {
+        matches!(pair[0], '!' | '*' | '+' | '?' | '@')
+            && pair[1] == '('
+            && closes_before(&chars[index + 2..], ')', '(')
+    })
+}
+
+// Whether `close` occurs in `chars` before any `stop`.
+fn closes_before(chars: &[char], close: char, stop: char) -> bool {
+    chars
+        .iter()
+        .find(|&&ch| ch == close || ch == stop)
+        .is_some_and(|&ch| ch == close)
+}
+
+// The text between the first `{` and the next `}` contains `,` or `..`.
+fn has_brace_expansion(pattern: &str) -> bool {
+    let Some(open) = pattern.find('{') else {
+        return false;
+    };
+    let Some(close) = pattern[open + 1..].find('}') else {
+        return false;
+    };
+    let content = &pattern[open..open + 1 + close];
+    content.contains(',') || content.contains("..")
+}
@@ -1,12 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod automaton;
+pub mod bases;
+pub mod candidate;
 pub mod compile;
 pub mod constants;
+pub mod depth;
+pub mod engine;
+pub mod examples;
+pub mod explain;
+pub mod gitignore;
+pub mod glob_parent;
+#[cfg(feature = "std")]
+pub mod ignore;
+pub mod infer;
+pub mod is_glob;
+pub mod limits;
+pub mod lint;
 pub mod matcher;
+pub mod overlap;
 pub mod scan;
+#[cfg(feature = "std")]
+pub mod static_matcher;
+pub mod strict;
 pub mod utils;
 
+pub use bases::glob_bases;
+pub use candidate::Candidate;
 pub use compile::{
     make_re, parse, regex_output_for_engine, CompileOptions, ParseState, ParseToken,
     RegexDescriptor,
 };
+pub use depth::{max_depth, Depth};
+pub use engine::Engine;
+pub use examples::Examples;
+pub use explain::{ExplainStep, Explainer, Explanation};
+pub use gitignore::{GitignoreMatch, GitignoreMatcher, GitignoreRule};
+pub use glob_parent::{glob_parent, GlobParentOptions};
+#[cfg(feature = "std")]
+pub use ignore::{IgnoreError, IgnoreTree, DEFAULT_IGNORE_FILES};
+pub use infer::infer_globs;
+pub use is_glob::{is_dynamic_pattern, is_extglob, is_glob, IsGlobOptions};
+pub use limits::{check_pattern_limits, check_regex_size};
+pub use lint::{lint, Diagnostic, DiagnosticKind};
 pub use matcher::{compile_matcher, is_match, is_match_any, MatchError, Matcher};
+pub use overlap::{glob_covers, globs_intersect};
 pub use scan::{scan, ScanOptions, ScanState, ScanToken};
+#[cfg(feature = "std")]
+pub use static_matcher::StaticMatcher;
+pub use strict::check_strict_glob;
+pub use utils::{escape_glob, escape_glob_windows, unescape_glob};
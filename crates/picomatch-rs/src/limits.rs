@@ -0,0 +1,114 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::{CompileOptions, MatchError};
+
+// Cheap structural checks that run before a pattern is compiled, so hostile
+// globs are rejected without building (or executing) a regex first.
+pub fn check_pattern_limits(pattern: &str, options: &CompileOptions) -> Result<(), MatchError> {
+    if options.max_nesting_depth.is_none() && options.max_alternations.is_none() {
+        return Ok(());
+    }
+
+    let (depth, alternations) = measure(pattern, options);
+
+    if let Some(limit) = options.max_nesting_depth {
+        if depth > limit {
+            return Err(MatchError::LimitExceeded(format!(
+                "nesting depth {depth} exceeds maximum of {limit}"
+            )));
+        }
+    }
+
+    if let Some(limit) = options.max_alternations {
+        if alternations > limit {
+            return Err(MatchError::LimitExceeded(format!(
+                "alternation count {alternations} exceeds maximum of {limit}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_regex_size(source: &str, options: &CompileOptions) -> Result<(), MatchError> {
+    match options.max_regex_size {
+        Some(limit) if source.len() > limit => Err(MatchError::LimitExceeded(format!(
+            "regex size {} exceeds maximum of {limit}",
+            source.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Brace,
+    Paren,
+}
+
+fn measure(pattern: &str, options: &CompileOptions) -> (usize, usize) {
+    let mut stack = Vec::new();
+    let mut depth = 0;
+    let mut alternations = 0;
+    let mut in_bracket = false;
+    let last_close = last_unescaped(pattern, ']');
+    let mut chars = pattern.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        if ch == '\\' {
+            chars.next();
+            continue;
+        }
+
+        if in_bracket {
+            if ch == ']' {
+                in_bracket = false;
+            }
+            continue;
+        }
+
+        match ch {
+            // An unclosed `[` is a literal, so its tail must still be counted.
+            '[' if !options.nobracket && last_close.is_some_and(|close| close > index + 1) => {
+                in_bracket = true;
+                if matches!(chars.peek(), Some((_, '!' | '^'))) {
+                    chars.next();
+                }
+                if matches!(chars.peek(), Some((close, ']')) if Some(*close) != last_close) {
+                    chars.next();
+                }
+            }
+            '{' if !options.nobrace => stack.push(Group::Brace),
+            '(' => stack.push(Group::Paren),
+            '}' if stack.last() == Some(&Group::Brace) => {
+                stack.pop();
+            }
+            ')' if stack.last() == Some(&Group::Paren) => {
+                stack.pop();
+            }
+            ',' if stack.last() == Some(&Group::Brace) => alternations += 1,
+            '|' => alternations += 1,
+            _ => {}
+        }
+
+        depth = depth.max(stack.len());
+    }
+
+    (depth, alternations)
+}
+
+fn last_unescaped(pattern: &str, target: char) -> Option<usize> {
+    let mut last = None;
+    let mut chars = pattern.char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        if ch == '\\' {
+            chars.next();
+        } else if ch == target {
+            last = Some(index);
+        }
+    }
+
+    last
+}
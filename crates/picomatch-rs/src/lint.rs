@@ -0,0 +1,212 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::compile::{
+    collect_bracket, collect_enclosed, split_negation, split_top_level, split_top_level_range,
+    MAX_RANGE_VALUES,
+};
+use crate::{compile_matcher, globs_intersect, CompileOptions};
+
+// Spans are byte offsets into the pattern, negation included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum DiagnosticKind {
+    GlobstarNotSegment,
+    BackslashSeparator,
+    UnclosedBracket,
+    SingleItemBrace,
+    DotSlashPrefix,
+    RangeTooLarge,
+    NeverMatches,
+    Invalid,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.start)
+    }
+}
+
+// Flags mistakes that `make_re` silently tolerates, such as `**` glued to
+// other characters or brackets that fall back to literals, in the order they
+// appear. Diagnostics from compiling the pattern come last.
+pub fn lint(pattern: &str, options: &CompileOptions) -> Vec<Diagnostic> {
+    let (positive, _) = split_negation(pattern, options);
+    let skipped = pattern.len() - positive.len();
+    let chars = positive.chars().collect::<Vec<_>>();
+    let offsets = positive
+        .char_indices()
+        .map(|(offset, _)| skipped + offset)
+        .chain(core::iter::once(pattern.len()))
+        .collect::<Vec<_>>();
+
+    let mut diagnostics = Vec::new();
+    let mut report = |kind, from: usize, to: usize, message: String| {
+        diagnostics.push(Diagnostic {
+            kind,
+            start: offsets[from],
+            end: offsets[to],
+            message,
+        });
+    };
+
+    if positive.starts_with("./") {
+        report(
+            DiagnosticKind::DotSlashPrefix,
+            0,
+            2,
+            "leading `./` is redundant because it is stripped before matching".to_string(),
+        );
+    }
+
+    let mut index = 0usize;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => {
+                if !options.windows && is_windows_separator(&chars, index) {
+                    report(
+                        DiagnosticKind::BackslashSeparator,
+                        index,
+                        index + 1,
+                        "`\\` escapes the next character; use `/` to separate directories, or \
+                         set `windows`"
+                            .to_string(),
+                    );
+                }
+                index += 2;
+                continue;
+            }
+            '[' if !options.nobracket => {
+                if let Some((_, end)) = collect_bracket(&chars, index) {
+                    index = end;
+                    continue;
+                }
+                report(
+                    DiagnosticKind::UnclosedBracket,
+                    index,
+                    index + 1,
+                    "`[` is never closed and matches itself literally; escape it as `\\[`"
+                        .to_string(),
+                );
+            }
+            '{' if !options.nobrace => {
+                if let Some((inner, end)) = collect_enclosed(&chars, index, '{', '}') {
+                    if let Some((start, stop)) = split_top_level_range(&inner) {
+                        if let Some(count) = range_len(&start, &stop) {
+                            if count > MAX_RANGE_VALUES {
+                                report(
+                                    DiagnosticKind::RangeTooLarge,
+                                    index,
+                                    end,
+                                    format!(
+                                        "range `{{{inner}}}` has {count} values, more than the \
+                                         {MAX_RANGE_VALUES} that can be expanded"
+                                    ),
+                                );
+                            }
+                        }
+                    } else if split_top_level(&inner, ',').len() == 1 {
+                        report(
+                            DiagnosticKind::SingleItemBrace,
+                            index,
+                            end,
+                            format!(
+                                "`{{{inner}}}` has a single item and matches itself literally, \
+                                 braces included"
+                            ),
+                        );
+                    }
+                }
+            }
+            '*' => {
+                let end = index + chars[index..].iter().take_while(|&&ch| ch == '*').count();
+                let before = index.checked_sub(1).map(|prev| chars[prev]);
+                let after = chars.get(end).copied();
+                let opens = matches!(before, None | Some('/' | '{' | ',' | '(' | '|'));
+                let closes = matches!(after, None | Some('/' | '}' | ',' | ')' | '|'));
+                // `**(` is a star followed by the `*(` extglob.
+                if end - index > 1
+                    && !options.noglobstar
+                    && after != Some('(')
+                    && !(opens && closes)
+                {
+                    report(
+                        DiagnosticKind::GlobstarNotSegment,
+                        index,
+                        end,
+                        "`**` only crosses directories as a whole segment; here it acts like `*`"
+                            .to_string(),
+                    );
+                }
+                index = end;
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    match compile_matcher(pattern, options) {
+        Err(error) => {
+            if !diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.kind == DiagnosticKind::RangeTooLarge)
+            {
+                diagnostics.push(whole(pattern, DiagnosticKind::Invalid, error.to_string()));
+            }
+        }
+        // Patterns the glob engine cannot explore are given the benefit of
+        // the doubt.
+        Ok(_) => {
+            if let Ok(None) = globs_intersect(pattern, pattern, options) {
+                diagnostics.push(whole(
+                    pattern,
+                    DiagnosticKind::NeverMatches,
+                    "no path matches this pattern other than its own spelling".to_string(),
+                ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+// A backslash between name characters, as in `src\lib`, or after `**`, as in
+// `**\*.js`, is far more likely a Windows separator than an escape.
+fn is_windows_separator(chars: &[char], index: usize) -> bool {
+    let name = |ch: Option<&char>| ch.is_some_and(|ch| ch.is_alphanumeric() || *ch == '_');
+    let prev = index.checked_sub(1).and_then(|prev| chars.get(prev));
+    let next = chars.get(index + 1);
+
+    (name(prev) && name(next)) || (index >= 2 && chars[index - 2..index] == ['*', '*'])
+}
+
+fn range_len(start: &str, stop: &str) -> Option<u64> {
+    let (start, stop) = (start.parse::<i64>().ok()?, stop.parse::<i64>().ok()?);
+    Some(start.abs_diff(stop).saturating_add(1))
+}
+
+fn whole(pattern: &str, kind: DiagnosticKind, message: String) -> Diagnostic {
+    Diagnostic {
+        kind,
+        start: 0,
+        end: pattern.len(),
+        message,
+    }
+}
@@ -1,22 +1,180 @@
-use fancy_regex::Regex;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::ffi::OsStr;
+#[cfg(feature = "std")]
+use std::path::Path;
 
-use crate::{make_re, CompileOptions};
+#[cfg(feature = "regex")]
+use fancy_regex::{Error as RegexError, Regex, RegexBuilder, RuntimeError};
+
+use crate::candidate::{basename_range, Candidate};
+use crate::compile::split_negation;
+use crate::engine::{Engine, GlobProgram};
+use crate::examples::{examples, Examples};
+use crate::explain::{Explainer, Explanation};
+use crate::limits::{check_pattern_limits, check_regex_size};
+use crate::strict::check_strict_glob;
+use crate::utils::decode_path_bytes;
+use crate::{make_re, CompileOptions, RegexDescriptor};
 
 #[derive(Debug)]
 pub enum MatchError {
     EmptyPattern,
     UnsupportedPattern(String),
     InvalidRegex(String),
+    LimitExceeded(String),
+    DisallowedSyntax(String),
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchError::EmptyPattern => f.write_str("pattern must be a non-empty string"),
+            MatchError::UnsupportedPattern(pattern) => write!(f, "unsupported pattern: {pattern}"),
+            MatchError::InvalidRegex(message) => write!(f, "invalid regex: {message}"),
+            MatchError::LimitExceeded(message) => write!(f, "limit exceeded: {message}"),
+            MatchError::DisallowedSyntax(message) => write!(f, "disallowed syntax: {message}"),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for MatchError {}
+
+#[derive(Debug, Clone)]
+enum Backend {
+    Glob(GlobProgram),
+    #[cfg(feature = "regex")]
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone)]
 pub struct Matcher {
     glob: String,
     options: CompileOptions,
-    regex: Regex,
+    backend: Backend,
+    negated: bool,
+    dir_only: bool,
+    extension: Option<String>,
 }
 
 impl Matcher {
+    pub fn engine(&self) -> Engine {
+        match self.backend {
+            Backend::Glob(_) => Engine::Glob,
+            #[cfg(feature = "regex")]
+            Backend::Regex(_) => Engine::Regex,
+        }
+    }
+
+    pub(crate) fn glob(&self) -> &str {
+        &self.glob
+    }
+
+    pub(crate) fn options(&self) -> &CompileOptions {
+        &self.options
+    }
+
+    // The glob program for this pattern, compiled on demand when the matcher
+    // runs on the regex engine.
+    pub(crate) fn program(&self) -> Option<Cow<'_, GlobProgram>> {
+        match &self.backend {
+            Backend::Glob(program) => Some(Cow::Borrowed(program)),
+            #[cfg(feature = "regex")]
+            Backend::Regex(_) => {
+                let descriptor = make_re(&self.glob, &self.options, false)?;
+                GlobProgram::new(&descriptor.source, &descriptor.flags).map(Cow::Owned)
+            }
+        }
+    }
+
     pub fn is_match(&self, input: &str) -> Result<bool, MatchError> {
+        self.is_match_parts(input, basename(input, self.options.windows), None)
+    }
+
+    // Reuses the path, basename and extension computed by `Candidate::new`,
+    // so a single candidate can be tested against many matchers cheaply.
+    // Literal and extension mismatches are answered without allocating; the
+    // engines may still allocate scratch space while matching.
+    pub fn is_match_candidate(&self, candidate: &Candidate<'_>) -> Result<bool, MatchError> {
+        let windows = self.options.windows;
+        let basename = candidate.basename(windows);
+        let extension = candidate.extension(windows);
+
+        let Some(is_dir) = candidate.is_dir() else {
+            return self.is_match_parts(candidate.path(), basename, extension);
+        };
+
+        let subject = candidate.subject(windows);
+        if subject.is_empty() {
+            return self.is_match(candidate.path());
+        }
+
+        if !is_dir {
+            if self.dir_only {
+                return Ok(self.negated);
+            }
+            return self.is_match_parts(subject, basename, extension);
+        }
+
+        let bare = self.is_match_parts(subject, basename, extension)?;
+        if bare != self.negated {
+            return Ok(bare);
+        }
+
+        match candidate.view(windows).slashed.as_deref() {
+            Some(slashed) => self.is_match_parts(slashed, basename, extension),
+            None => Ok(bare),
+        }
+    }
+
+    // Directories match when either their bare or their slash-terminated
+    // spelling matches, so `build/` and `strict_slashes` globs behave alike.
+    // Patterns ending in a slash never match files.
+    #[cfg(feature = "std")]
+    pub fn is_match_path<P: AsRef<Path>>(&self, path: P, is_dir: bool) -> Result<bool, MatchError> {
+        self.is_match_candidate(&Candidate::from_path(path.as_ref(), is_dir))
+    }
+
+    // Valid UTF-8 is matched as is. Invalid bytes are decoded with
+    // `utils::decode_path_bytes`, so they are matched by wildcards but never
+    // by literals.
+    pub fn is_match_bytes(&self, input: &[u8]) -> Result<bool, MatchError> {
+        self.is_match(&decode_path_bytes(input))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn is_match_os_str(&self, input: &OsStr) -> Result<bool, MatchError> {
+        self.is_match_bytes(input.as_encoded_bytes())
+    }
+
+    // Replays the pattern segment by segment to say why `input` did or did
+    // not match. The verdict itself always comes from `is_match`.
+    pub fn explain(&self, input: &str) -> Result<Explanation, MatchError> {
+        self.explainer().explain(input)
+    }
+
+    // Like `explain`, but keeps the matchers built for the replay and the
+    // hints, for explaining many inputs against this pattern.
+    pub fn explainer(&self) -> Explainer<'_> {
+        Explainer::new(self)
+    }
+
+    // Up to `count` paths that match, spelled out from the pattern's
+    // wildcards, classes, braces and extglobs, and up to `count` near misses
+    // that fail. Every path is checked with `is_match`.
+    pub fn examples(&self, count: usize) -> Result<Examples, MatchError> {
+        examples(self, &self.glob, &self.options, count)
+    }
+
+    fn is_match_parts(
+        &self,
+        input: &str,
+        basename: &str,
+        extension: Option<&str>,
+    ) -> Result<bool, MatchError> {
         if input.is_empty() {
             return Ok(false);
         }
@@ -25,15 +183,28 @@ impl Matcher {
             return Ok(true);
         }
 
-        let candidate = if self.options.match_base || self.options.basename {
-            basename(input, self.options.windows)
+        if let (Some(expected), Some(actual)) = (self.extension.as_deref(), extension) {
+            if expected != actual {
+                return Ok(false);
+            }
+        }
+
+        let subject = if self.options.match_base || self.options.basename {
+            basename
         } else {
-            input.to_string()
+            input
         };
 
-        self.regex
-            .is_match(&candidate)
-            .map_err(|err| MatchError::InvalidRegex(err.to_string()))
+        match &self.backend {
+            Backend::Glob(program) => Ok(program.is_match(subject)),
+            #[cfg(feature = "regex")]
+            Backend::Regex(regex) => regex.is_match(subject).map_err(|err| match err {
+                RegexError::RuntimeError(RuntimeError::BacktrackLimitExceeded) => {
+                    MatchError::LimitExceeded("backtrack limit exceeded".to_string())
+                }
+                err => MatchError::InvalidRegex(err.to_string()),
+            }),
+        }
     }
 }
 
@@ -42,18 +213,75 @@ pub fn compile_matcher(pattern: &str, options: &CompileOptions) -> Result<Matche
         return Err(MatchError::EmptyPattern);
     }
 
+    check_pattern_limits(pattern, options)?;
+    check_strict_glob(pattern, options)?;
+
     let descriptor = make_re(pattern, options, false)
         .ok_or_else(|| MatchError::UnsupportedPattern(pattern.to_string()))?;
-    let regex = Regex::new(&regex_source(&descriptor.source, &descriptor.flags))
-        .map_err(|err| MatchError::InvalidRegex(err.to_string()))?;
+    check_regex_size(&descriptor.source, options)?;
+    let backend = compile_backend(pattern, &descriptor, options)?;
+
+    let (positive, negated) = split_negation(pattern, options);
+    let dir_only = positive.len() > 1 && positive.ends_with('/') && !positive.ends_with("\\/");
+    let extension = (!negated)
+        .then(|| literal_extension(positive, options))
+        .flatten();
 
     Ok(Matcher {
         glob: pattern.to_string(),
         options: options.clone(),
-        regex,
+        backend,
+        negated,
+        dir_only,
+        extension,
     })
 }
 
+// `Engine::Auto` runs on the regex engine when it is built in, and on the glob
+// engine otherwise.
+fn compile_backend(
+    pattern: &str,
+    descriptor: &RegexDescriptor,
+    options: &CompileOptions,
+) -> Result<Backend, MatchError> {
+    let glob = || GlobProgram::new(&descriptor.source, &descriptor.flags).map(Backend::Glob);
+
+    match options.engine {
+        Engine::Auto if cfg!(feature = "regex") => compile_regex(pattern, descriptor, options),
+        Engine::Auto | Engine::Glob => {
+            glob().ok_or_else(|| MatchError::UnsupportedPattern(pattern.to_string()))
+        }
+        Engine::Regex => compile_regex(pattern, descriptor, options),
+    }
+}
+
+#[cfg(feature = "regex")]
+fn compile_regex(
+    _pattern: &str,
+    descriptor: &RegexDescriptor,
+    options: &CompileOptions,
+) -> Result<Backend, MatchError> {
+    let source = regex_source(&descriptor.source, &descriptor.flags);
+    let mut builder = RegexBuilder::new(&source);
+    if let Some(limit) = options.backtrack_limit {
+        builder.backtrack_limit(limit);
+    }
+    builder
+        .build()
+        .map(Backend::Regex)
+        .map_err(|err| MatchError::InvalidRegex(err.to_string()))
+}
+
+#[cfg(not(feature = "regex"))]
+fn compile_regex(
+    pattern: &str,
+    _descriptor: &RegexDescriptor,
+    _options: &CompileOptions,
+) -> Result<Backend, MatchError> {
+    Err(MatchError::UnsupportedPattern(pattern.to_string()))
+}
+
+#[cfg(feature = "regex")]
 fn regex_source(source: &str, flags: &str) -> String {
     if flags.contains('i') {
         format!("(?i){source}")
@@ -83,18 +311,33 @@ where
     Ok(false)
 }
 
-fn basename(input: &str, windows: bool) -> String {
-    let sep: &[char] = if windows { &['/', '\\'] } else { &['/'] };
-    let mut parts = input.rsplit(sep);
-    match parts.next() {
-        Some("") => parts.next().unwrap_or_default().to_string(),
-        Some(value) => value.to_string(),
-        None => String::new(),
+// `*.ext` and `**/*.ext` can only match candidates with that exact
+// extension, which lets `is_match_candidate` skip the regex entirely.
+fn literal_extension(pattern: &str, options: &CompileOptions) -> Option<String> {
+    if options.nocase || options.contains || options.regex || options.flags.contains('i') {
+        return None;
     }
+
+    let extension = pattern
+        .strip_prefix("**/")
+        .unwrap_or(pattern)
+        .strip_prefix("*.")?;
+    let is_literal = !extension.is_empty()
+        && extension
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    is_literal.then(|| extension.to_string())
+}
+
+fn basename(input: &str, windows: bool) -> &str {
+    let (start, end) = basename_range(input, windows);
+    &input[start..end]
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use super::{basename, is_match, is_match_any};
     use crate::CompileOptions;
 
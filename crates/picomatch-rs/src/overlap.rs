@@ -0,0 +1,100 @@
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::automaton::{alphabet, Automaton};
+use crate::engine::Engine;
+use crate::{compile_matcher, CompileOptions, MatchError, Matcher};
+
+// Product states explored before giving up on a pair of globs.
+const MAX_STATES: usize = 100_000;
+
+// Decides whether some path matches both globs and returns a shortest such
+// path. Both globs are compiled for the glob engine, whose programs are
+// explored as automata; patterns that need the regex engine are rejected
+// with `MatchError::UnsupportedPattern`. Every witness is confirmed with
+// `Matcher::is_match` before it is returned. The shortcut that makes a path
+// spelled exactly like a glob match it is not considered an overlap.
+pub fn globs_intersect(
+    a: &str,
+    b: &str,
+    options: &CompileOptions,
+) -> Result<Option<String>, MatchError> {
+    find_path(a, b, options, |a, b| a && b)
+}
+
+// Whether every path `b` matches is also matched by `a`, so that `b` is
+// redundant next to `a`, or a negation `!b` after `a` can never take effect.
+// Uses the same search as `globs_intersect`, looking for a path only `b`
+// matches.
+pub fn glob_covers(a: &str, b: &str, options: &CompileOptions) -> Result<bool, MatchError> {
+    Ok(find_path(a, b, options, |a, b| !a && b)?.is_none())
+}
+
+// Walks the product of both automata breadth-first for a shortest path whose
+// verdicts satisfy `wanted`.
+fn find_path(
+    a: &str,
+    b: &str,
+    options: &CompileOptions,
+    wanted: impl Fn(bool, bool) -> bool,
+) -> Result<Option<String>, MatchError> {
+    let options = CompileOptions {
+        engine: Engine::Glob,
+        ..options.clone()
+    };
+    let matchers = [compile_matcher(a, &options)?, compile_matcher(b, &options)?];
+    let automata = [automaton(&matchers[0], a)?, automaton(&matchers[1], b)?];
+    let chars = alphabet(&[&automata[0], &automata[1]]);
+
+    // A dead automaton rejects every extension of the path, which may rule
+    // out ever reaching a wanted pair of verdicts.
+    let verdicts = |dead: bool| {
+        if dead {
+            &[false][..]
+        } else {
+            &[false, true][..]
+        }
+    };
+    let reachable = |dead: [bool; 2]| {
+        verdicts(dead[0])
+            .iter()
+            .any(|&left| verdicts(dead[1]).iter().any(|&right| wanted(left, right)))
+    };
+
+    let start = (automata[0].start(), automata[1].start());
+    let mut seen = BTreeSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, String::new())]);
+
+    while let Some(((left, right), path)) = queue.pop_front() {
+        for &ch in &chars {
+            let next = (automata[0].step(&left, ch), automata[1].step(&right, ch));
+            if !reachable([automata[0].is_dead(&next.0), automata[1].is_dead(&next.1)]) {
+                continue;
+            }
+
+            let mut path = path.clone();
+            path.push(ch);
+            if wanted(automata[0].accepts(&next.0), automata[1].accepts(&next.1))
+                && wanted(matchers[0].is_match(&path)?, matchers[1].is_match(&path)?)
+            {
+                return Ok(Some(path));
+            }
+
+            if seen.insert(next.clone()) {
+                if seen.len() > MAX_STATES {
+                    return Err(MatchError::LimitExceeded(format!(
+                        "comparing `{a}` and `{b}` explored more than {MAX_STATES} states"
+                    )));
+                }
+                queue.push_back((next, path));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn automaton<'a>(matcher: &'a Matcher, glob: &str) -> Result<Automaton<'a>, MatchError> {
+    Automaton::new(matcher).ok_or_else(|| MatchError::UnsupportedPattern(glob.to_string()))
+}
@@ -1,3 +1,7 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::constants::{
@@ -6,10 +10,12 @@ use crate::constants::{
     CHAR_PLUS, CHAR_QUESTION_MARK, CHAR_RIGHT_CURLY_BRACE, CHAR_RIGHT_PARENTHESES,
     CHAR_RIGHT_SQUARE_BRACKET,
 };
+use crate::depth::Depth;
 use crate::utils::{is_path_separator, remove_backslashes};
 
-#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
-#[serde(default, rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct ScanOptions {
     pub parts: bool,
     pub tokens: bool,
@@ -20,33 +26,37 @@ pub struct ScanOptions {
     pub unescape: bool,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScanToken {
     pub value: String,
-    pub depth: f64,
+    pub depth: Depth,
     pub is_glob: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub is_globstar: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub is_brace: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub is_bracket: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub is_extglob: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub negated: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub backslashes: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub is_prefix: Option<bool>,
+    // Byte offsets of `value` in the scanned input.
+    pub start: usize,
+    pub end: usize,
 }
 
 impl Default for ScanToken {
     fn default() -> Self {
         Self {
             value: String::new(),
-            depth: 0.0,
+            depth: Depth::Finite(0),
             is_glob: false,
             is_globstar: None,
             is_brace: None,
@@ -55,12 +65,15 @@ impl Default for ScanToken {
             negated: None,
             backslashes: None,
             is_prefix: None,
+            start: 0,
+            end: 0,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScanState {
     pub prefix: String,
     pub input: String,
@@ -74,13 +87,13 @@ pub struct ScanState {
     pub is_globstar: bool,
     pub negated: bool,
     pub negated_extglob: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_depth: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub max_depth: Option<Depth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub tokens: Option<Vec<ScanToken>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub slashes: Option<Vec<usize>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub parts: Option<Vec<String>>,
 }
 
@@ -132,9 +145,9 @@ impl<'a> InputView<'a> {
 fn depth(token: &mut ScanToken) {
     if token.is_prefix != Some(true) {
         token.depth = if token.is_globstar == Some(true) {
-            f64::INFINITY
+            Depth::Unbounded
         } else {
-            1.0
+            Depth::Finite(1)
         };
     }
 }
@@ -468,10 +481,12 @@ pub fn scan(input: &str, options: &ScanOptions) -> ScanState {
         glob = str.clone();
     }
 
-    if !base.is_empty() && base != "/" && base != str {
-        if base.chars().last().is_some_and(is_path_separator) {
-            base.pop();
-        }
+    if !base.is_empty()
+        && base != "/"
+        && base != str
+        && base.chars().last().is_some_and(is_path_separator)
+    {
+        base.pop();
     }
 
     if options.unescape {
@@ -504,7 +519,7 @@ pub fn scan(input: &str, options: &ScanOptions) -> ScanState {
     };
 
     if options.tokens {
-        state.max_depth = Some(0.0);
+        state.max_depth = Some(Depth::Finite(0));
         if !is_path_separator(code) {
             tokens.push(token);
         }
@@ -524,8 +539,11 @@ pub fn scan(input: &str, options: &ScanOptions) -> ScanState {
                 if idx == 0 && start != 0 {
                     tokens[idx].is_prefix = Some(true);
                     tokens[idx].value = state.prefix.clone();
+                    tokens[idx].end = view.offsets[start];
                 } else {
                     tokens[idx].value = value.clone();
+                    tokens[idx].start = view.offsets[n];
+                    tokens[idx].end = view.offsets[slash_index];
                 }
                 depth(&mut tokens[idx]);
                 if let Some(max_depth) = state.max_depth.as_mut() {
@@ -547,6 +565,8 @@ pub fn scan(input: &str, options: &ScanOptions) -> ScanState {
                 if options.tokens {
                     let last = tokens.len() - 1;
                     tokens[last].value = value;
+                    tokens[last].start = view.offsets[prev_index_value + 1];
+                    tokens[last].end = input.len();
                     depth(&mut tokens[last]);
                     if let Some(max_depth) = state.max_depth.as_mut() {
                         *max_depth += tokens[last].depth;
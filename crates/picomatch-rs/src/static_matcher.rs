@@ -0,0 +1,44 @@
+use std::sync::LazyLock;
+
+use crate::{MatchError, Matcher};
+
+// Built by the `glob!` macro in `picomatch-rs-macros`, which has already
+// validated the pattern. Literal and `*.ext` / `**/*.ext` patterns compiled
+// with default options are recognised by the macro and matched directly;
+// everything else compiles into a `Matcher` on first use. Values only ever
+// live in statics, so the size of the lazy variant does not matter.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum StaticMatcher {
+    Literal(&'static str),
+    Suffix(&'static str),
+    RecursiveSuffix(&'static str),
+    Lazy(LazyLock<Matcher>),
+}
+
+impl StaticMatcher {
+    pub fn is_match(&self, input: &str) -> Result<bool, MatchError> {
+        match self {
+            StaticMatcher::Literal(literal) => Ok(input == *literal),
+            StaticMatcher::Suffix(suffix) => {
+                Ok(!input.contains('/') && is_suffix_match(input, suffix))
+            }
+            StaticMatcher::RecursiveSuffix(suffix) => {
+                let path = input.strip_prefix('/').unwrap_or(input);
+                let mut segments = path.split('/');
+                let name = segments.next_back().unwrap_or_default();
+                Ok(
+                    segments.all(|segment| !segment.is_empty() && !segment.starts_with('.'))
+                        && is_suffix_match(name, suffix),
+                )
+            }
+            StaticMatcher::Lazy(matcher) => matcher.is_match(input),
+        }
+    }
+}
+
+// Mirrors the `(?!\.)(?=.)[^/]*?` prefix `make_re` emits for `*`: the name may
+// not start with a dot, and `.` in the lookahead does not match a newline.
+fn is_suffix_match(name: &str, suffix: &str) -> bool {
+    !name.starts_with(['.', '\n']) && name.ends_with(suffix)
+}
@@ -0,0 +1,172 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::compile::{collect_bracket, is_regex_escape, posix_class_source};
+use crate::{CompileOptions, MatchError};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Prev {
+    Other,
+    Bracket,
+    Group,
+    ExtglobOperator,
+}
+
+// `make_re` passes several regex constructs through verbatim for picomatch
+// compatibility. With `strict_glob` set, any of them is rejected up front so
+// untrusted globs cannot smuggle arbitrary regex into the compiled output.
+pub fn check_strict_glob(pattern: &str, options: &CompileOptions) -> Result<(), MatchError> {
+    if !options.strict_glob {
+        return Ok(());
+    }
+
+    if options.regex {
+        return Err(disallowed(0, "the `regex` option is not allowed"));
+    }
+
+    let offsets = pattern
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .collect::<Vec<_>>();
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut prev = Prev::Other;
+    let mut extglob_depth = 0usize;
+    let mut brace_depth = 0usize;
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let offset = offsets[index];
+        let next = chars.get(index + 1).copied();
+
+        match ch {
+            '\\' => {
+                if let Some(next) = next.filter(|next| is_regex_escape(*next)) {
+                    return Err(disallowed(
+                        offset,
+                        &format!("regex escape `\\{next}` is not allowed"),
+                    ));
+                }
+                prev = Prev::Other;
+                index += 2;
+                continue;
+            }
+            '[' if !options.nobracket => {
+                if let Some((inner, next_index)) = collect_bracket(&chars, index) {
+                    check_bracket(&inner, offset, options)?;
+                    prev = Prev::Bracket;
+                    index = next_index;
+                    continue;
+                }
+                if next == Some('[') && chars.get(index + 2) == Some(&':') {
+                    return Err(disallowed(offset, "unterminated POSIX class"));
+                }
+            }
+            '(' => {
+                if next == Some('?') {
+                    return Err(disallowed(offset, "raw regex group `(?` is not allowed"));
+                }
+                if prev != Prev::ExtglobOperator {
+                    return Err(disallowed(
+                        offset,
+                        "regex group is not allowed; use `@(a|b)` or `{a,b}`, or escape `\\(`",
+                    ));
+                }
+                extglob_depth += 1;
+                prev = Prev::Other;
+                index += 1;
+                continue;
+            }
+            ')' if extglob_depth > 0 => {
+                extglob_depth -= 1;
+                prev = Prev::Group;
+                index += 1;
+                continue;
+            }
+            '{' if !options.nobrace => brace_depth += 1,
+            '}' if brace_depth > 0 => {
+                brace_depth -= 1;
+                prev = Prev::Group;
+                index += 1;
+                continue;
+            }
+            '|' if extglob_depth == 0 => {
+                return Err(disallowed(
+                    offset,
+                    "`|` outside an extglob is regex alternation; use `{a,b}`",
+                ));
+            }
+            '?' | '+' if next != Some('(') || options.noextglob => {
+                if matches!(prev, Prev::Bracket | Prev::Group) {
+                    return Err(disallowed(
+                        offset,
+                        &format!("`{ch}` after a class or group is a regex quantifier"),
+                    ));
+                }
+                if ch == '+' && extglob_depth > 0 {
+                    return Err(disallowed(
+                        offset,
+                        "`+` inside an extglob is a regex quantifier; escape it as `\\+`",
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        prev = if matches!(ch, '@' | '!' | '?' | '*' | '+') && !options.noextglob {
+            Prev::ExtglobOperator
+        } else {
+            Prev::Other
+        };
+        index += 1;
+    }
+
+    Ok(())
+}
+
+fn check_bracket(inner: &str, offset: usize, options: &CompileOptions) -> Result<(), MatchError> {
+    let chars = inner.chars().collect::<Vec<_>>();
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let next = chars.get(index + 1).copied();
+
+        if ch == '\\' {
+            if let Some(next) = next.filter(|next| is_regex_escape(*next)) {
+                return Err(disallowed(
+                    offset,
+                    &format!("regex escape `\\{next}` inside a bracket is not allowed"),
+                ));
+            }
+            index += 2;
+            continue;
+        }
+
+        if ch == '[' && next == Some(':') {
+            let rest = chars[index + 2..].iter().collect::<String>();
+            let name = rest.split_once(":]").map(|(name, _)| name);
+            if !options.posix || name.and_then(posix_class_source).is_none() {
+                return Err(disallowed(offset, "unknown POSIX class"));
+            }
+            index += 2 + name.map_or(0, |name| name.chars().count()) + 2;
+            continue;
+        }
+
+        if matches!(ch, '&' | '-' | '~') && next == Some(ch) {
+            return Err(disallowed(
+                offset,
+                &format!("`{ch}{ch}` inside a bracket is a regex set operation"),
+            ));
+        }
+
+        index += 1;
+    }
+
+    Ok(())
+}
+
+fn disallowed(offset: usize, message: &str) -> MatchError {
+    MatchError::DisallowedSyntax(format!("{message} (at byte {offset})"))
+}
@@ -1,9 +1,147 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::constants::{CHAR_BACKWARD_SLASH, CHAR_FORWARD_SLASH};
 
+const RAW_BYTE_BASE: u32 = 0x10FF00;
+
 pub fn is_path_separator(ch: char) -> bool {
     ch == CHAR_FORWARD_SLASH || ch == CHAR_BACKWARD_SLASH
 }
 
+// Decodes arbitrary bytes for matching. Valid UTF-8 is returned unchanged;
+// each byte of an invalid sequence becomes the private use code point
+// U+10FF00 + byte. Invalid bytes are never ASCII, so separators, dots and
+// other glob-relevant characters keep their meaning.
+pub fn decode_path_bytes(bytes: &[u8]) -> Cow<'_, str> {
+    let mut rest = match core::str::from_utf8(bytes) {
+        Ok(valid) => return Cow::Borrowed(valid),
+        Err(_) => bytes,
+    };
+
+    let mut output = String::with_capacity(bytes.len() + 8);
+    while !rest.is_empty() {
+        let (valid, invalid) = match core::str::from_utf8(rest) {
+            Ok(valid) => (valid, &[][..]),
+            Err(err) => {
+                let (valid, invalid) = rest.split_at(err.valid_up_to());
+                (core::str::from_utf8(valid).unwrap_or_default(), invalid)
+            }
+        };
+        output.push_str(valid);
+
+        let invalid_len = match core::str::from_utf8(invalid) {
+            Err(err) => err.error_len().unwrap_or(invalid.len()),
+            Ok(_) => invalid.len(),
+        };
+        for &byte in &invalid[..invalid_len] {
+            output.push(char::from_u32(RAW_BYTE_BASE + byte as u32).unwrap_or('\u{FFFD}'));
+        }
+        rest = &invalid[invalid_len..];
+    }
+
+    Cow::Owned(output)
+}
+
+// The inverse of `decode_path_bytes`, for input that does not itself contain
+// code points U+10FF80..=U+10FFFF, which are read back as raw bytes.
+pub fn encode_path_bytes(input: &str) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len());
+    for ch in input.chars() {
+        if ch as u32 >= RAW_BYTE_BASE + 0x80 {
+            output.push((ch as u32 - RAW_BYTE_BASE) as u8);
+        } else {
+            let mut buffer = [0u8; 4];
+            output.extend_from_slice(ch.encode_utf8(&mut buffer).as_bytes());
+        }
+    }
+    output
+}
+
+// Turns a literal path into a glob matching exactly that path, for file
+// names such as `page[id].tsx`. The path should be normalized, since globs
+// never match `.` or `..` segments. Backslashes are escaped too, so the
+// result is meant for posix mode; use `escape_glob_windows` for Windows paths.
+pub fn escape_glob(path: &str) -> String {
+    escape_glob_chars(path, true)
+}
+
+// Like `escape_glob`, but for Windows paths matched with `windows` set:
+// backslashes are separators, and are written as `/` so that they cannot
+// be read as escapes.
+pub fn escape_glob_windows(path: &str) -> String {
+    escape_glob_chars(&path.replace(CHAR_BACKWARD_SLASH, "/"), false)
+}
+
+// The inverse of `escape_glob`: drops every escaping backslash, including
+// those inside brackets, which `remove_backslashes` keeps.
+pub fn unescape_glob(pattern: &str) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(ch) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("[\\\\]") {
+            result.push(CHAR_BACKWARD_SLASH);
+            rest = after;
+            continue;
+        }
+        rest = &rest[ch.len_utf8()..];
+        if ch == CHAR_BACKWARD_SLASH {
+            if let Some(next) = rest.chars().next() {
+                result.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+            continue;
+        }
+        result.push(ch);
+    }
+
+    result
+}
+
+// `!`, `+` and `@` only need escaping where they start a negation or an
+// extglob; `,` is only special inside braces, whose `{` is escaped.
+// Quotes are escaped because the compiler drops bare ones. The compiler also
+// collapses runs of more than three backslashes, so every second backslash
+// of a run is written as the class `[\\]`.
+fn escape_glob_chars(path: &str, escape_backslashes: bool) -> String {
+    let mut result = String::with_capacity(path.len() + 8);
+    let mut chars = path.chars().peekable();
+    let mut first = true;
+    // Whether the last character was a backslash written as `[\\]`.
+    let mut after_class = false;
+    let mut odd_backslash = false;
+
+    while let Some(ch) = chars.next() {
+        if ch == CHAR_BACKWARD_SLASH && escape_backslashes {
+            after_class = odd_backslash;
+            odd_backslash = !odd_backslash;
+            result.push_str(if after_class { "[\\\\]" } else { "\\\\" });
+            first = false;
+            continue;
+        }
+        odd_backslash = false;
+
+        let special = match ch {
+            '*' | '?' | '[' | ']' | '{' | '}' | '(' | ')' | '|' | '"' | '\'' => true,
+            '!' => first || chars.peek() == Some(&'('),
+            // A `+` after a class would repeat it.
+            '+' => after_class || chars.peek() == Some(&'('),
+            '@' => chars.peek() == Some(&'('),
+            _ => false,
+        };
+        if special {
+            result.push(CHAR_BACKWARD_SLASH);
+        }
+        result.push(ch);
+        first = false;
+        after_class = false;
+    }
+
+    result
+}
+
 pub fn remove_backslashes(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let mut chars = input.chars().peekable();
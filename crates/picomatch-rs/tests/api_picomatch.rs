@@ -614,6 +614,35 @@ fn test_parse_tokens_for_brace_pattern() {
     );
 }
 
+#[test]
+fn test_parse_tokens_carry_byte_spans() {
+    let pattern = "!é*/{a,b}";
+    let state = parse(pattern, &CompileOptions::default()).expect("parse state");
+    let tokens = state.tokens.expect("parse tokens");
+    let actual = tokens
+        .iter()
+        .map(|token| (token.kind.as_str(), token.start, token.end))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        actual,
+        vec![
+            ("bos", 1, 1),
+            ("text", 1, 3),
+            ("star", 3, 4),
+            ("slash", 4, 5),
+            ("brace", 5, 6),
+            ("text", 6, 7),
+            ("comma", 7, 8),
+            ("text", 8, 9),
+            ("brace", 9, 10),
+        ]
+    );
+    for token in &tokens {
+        assert_eq!(&pattern[token.start..token.end], token.value);
+    }
+}
+
 #[test]
 fn test_parse_tokens_with_output_field() {
     let state = parse("foo.(m|c|)js", &CompileOptions::default()).expect("parse state");
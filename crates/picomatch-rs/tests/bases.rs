@@ -0,0 +1,77 @@
+use picomatch_rs::{glob_bases, CompileOptions};
+
+fn bases(patterns: &[&str]) -> Vec<String> {
+    glob_bases(patterns, &CompileOptions::default())
+}
+
+#[test]
+fn merges_bases_inside_other_bases() {
+    assert_eq!(
+        bases(&["src/**/*.ts", "src/lib/*.js", "test/**"]),
+        ["src", "test"]
+    );
+    assert_eq!(bases(&["src/lib/*.js", "src/lib/util/*.js"]), ["src/lib"]);
+    assert_eq!(
+        bases(&["src/lib/*.js", "src/library/*.js"]),
+        ["src/lib", "src/library"]
+    );
+    assert_eq!(bases(&["src/**", "*.json"]), ["."]);
+    assert_eq!(bases(&["./docs/*.md", "docs/api/*.md"]), ["docs"]);
+    assert_eq!(bases(&[]), Vec::<String>::new());
+}
+
+#[test]
+fn expands_braces_before_taking_bases() {
+    assert_eq!(bases(&["{src,test}/**/*.ts"]), ["src", "test"]);
+    assert_eq!(
+        bases(&["packages/{a,b/{c,d}}/src/*.ts"]),
+        ["packages/a/src", "packages/b/c/src", "packages/b/d/src"]
+    );
+    assert_eq!(bases(&["src/*.{js,ts}"]), ["src"]);
+    assert_eq!(bases(&["logs/{1..3}/*.log"]), ["logs"]);
+
+    let nobrace = CompileOptions {
+        nobrace: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(glob_bases(&["{src,test}/**"], &nobrace), ["{src,test}"]);
+}
+
+#[test]
+fn literal_paths_contribute_their_directory() {
+    assert_eq!(bases(&["src/main.rs"]), ["src"]);
+    assert_eq!(bases(&["src/lib/"]), ["src/lib"]);
+    assert_eq!(bases(&["Cargo.toml"]), ["."]);
+    assert_eq!(bases(&["/etc/hosts", "/var/log/**"]), ["/etc", "/var/log"]);
+    assert_eq!(bases(&["app/\\[id\\]/*.tsx"]), ["app/[id]"]);
+}
+
+#[test]
+fn negations_drop_excluded_includes() {
+    assert_eq!(bases(&["src/**", "vendor/**", "!vendor/**"]), ["src"]);
+    assert_eq!(bases(&["{src,vendor}/**/*.js", "!vendor/**"]), ["src"]);
+    // A negation that only excludes part of an include leaves its base alone.
+    assert_eq!(bases(&["src/**", "!src/legacy/**"]), ["src"]);
+    assert_eq!(bases(&["!**/*.log"]), Vec::<String>::new());
+}
+
+#[test]
+fn basename_patterns_need_the_whole_tree() {
+    let options = CompileOptions {
+        match_base: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(glob_bases(&["src/*.ts", "*.md"], &options), ["."]);
+}
+
+#[test]
+fn windows_separators_are_normalized() {
+    let options = CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(
+        glob_bases(&["src\\lib/*.js", "src\\lib\\a/*.js"], &options),
+        ["src/lib"]
+    );
+}
@@ -0,0 +1,142 @@
+use picomatch_rs::{compile_matcher, Candidate, CompileOptions};
+
+const PATTERNS: [&str; 14] = [
+    "*.rs",
+    "**/*.rs",
+    "src/**/*.rs",
+    "!*.rs",
+    "*.{rs,toml}",
+    "*.RS",
+    "**/*.d.ts",
+    "*",
+    "**",
+    "a/*/c",
+    "build/",
+    "!build/",
+    ".*",
+    "*.rs/",
+];
+
+const INPUTS: [&str; 16] = [
+    "lib.rs",
+    "src/lib.rs",
+    "src/a/b/mod.rs",
+    "Cargo.toml",
+    ".rs",
+    "a.b.rs",
+    "a.rs.bak",
+    "index.d.ts",
+    "types/index.d.ts",
+    "a/b/c",
+    "build",
+    "build/",
+    ".hidden",
+    "dir.rs/",
+    "*.rs",
+    "/",
+];
+
+fn options_variants() -> Vec<CompileOptions> {
+    vec![
+        CompileOptions::default(),
+        CompileOptions {
+            windows: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            match_base: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            nocase: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            dot: true,
+            strict_slashes: true,
+            ..CompileOptions::default()
+        },
+    ]
+}
+
+#[test]
+fn precomputes_basename_and_extension() {
+    let candidate = Candidate::new("src/nested/mod.rs");
+    assert_eq!(candidate.path(), "src/nested/mod.rs");
+    assert_eq!(candidate.basename(false), "mod.rs");
+    assert_eq!(candidate.extension(false), Some("rs"));
+    assert_eq!(candidate.is_dir(), None);
+
+    let candidate = Candidate::new("a\\b\\c.tar.gz");
+    assert_eq!(candidate.basename(false), "a\\b\\c.tar.gz");
+    assert_eq!(candidate.basename(true), "c.tar.gz");
+    assert_eq!(candidate.extension(true), Some("gz"));
+
+    assert_eq!(Candidate::new(".gitignore").extension(false), None);
+    assert_eq!(Candidate::new("a/b/").basename(false), "b");
+    assert_eq!(Candidate::new("Makefile").extension(false), None);
+
+    let candidate = Candidate::with_dir_hint("target/", true);
+    assert_eq!(candidate.basename(false), "target");
+    assert_eq!(candidate.is_dir(), Some(true));
+}
+
+#[test]
+fn agrees_with_is_match() {
+    for options in options_variants() {
+        for pattern in PATTERNS {
+            let matcher = compile_matcher(pattern, &options).unwrap();
+            for input in INPUTS {
+                assert_eq!(
+                    matcher.is_match_candidate(&Candidate::new(input)).unwrap(),
+                    matcher.is_match(input).unwrap(),
+                    "{input:?} against {pattern:?} with {options:?}"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn directory_hints_agree_with_is_match_path() {
+    for options in options_variants() {
+        for pattern in PATTERNS {
+            let matcher = compile_matcher(pattern, &options).unwrap();
+            for input in INPUTS {
+                for is_dir in [false, true] {
+                    assert_eq!(
+                        matcher
+                            .is_match_candidate(&Candidate::with_dir_hint(input, is_dir))
+                            .unwrap(),
+                        matcher.is_match_path(input, is_dir).unwrap(),
+                        "{input:?} (is_dir: {is_dir}) against {pattern:?} with {options:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn reuses_one_candidate_across_matchers() {
+    let options = CompileOptions::default();
+    let matchers = ["*.md", "**/*.rs", "src/**", "!**/*.rs"]
+        .map(|pattern| compile_matcher(pattern, &options).unwrap());
+    let candidate = Candidate::from_path(std::path::Path::new("src/bin/main.rs"), false);
+
+    let results = matchers
+        .iter()
+        .map(|matcher| matcher.is_match_candidate(&candidate).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(results, [false, true, true, false]);
+}
+
+#[test]
+fn byte_candidates_decode_invalid_utf8() {
+    let matcher = compile_matcher("logs/*.log", &CompileOptions::default()).unwrap();
+    let candidate = Candidate::from_bytes(b"logs/\xff.log");
+    assert_eq!(candidate.extension(false), Some("log"));
+    assert!(matcher.is_match_candidate(&candidate).unwrap());
+}
@@ -0,0 +1,109 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use picomatch_rs::{compile_matcher, Candidate, CompileOptions, Engine, Matcher};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Allocations made by the second of two identical calls, so that caches the
+// engines fill on first use are not counted.
+fn allocations(mut call: impl FnMut() -> bool) -> usize {
+    call();
+    let before = ALLOCATIONS.with(Cell::get);
+    call();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn engines() -> Vec<Engine> {
+    let mut engines = vec![Engine::Glob];
+    if cfg!(feature = "regex") {
+        engines.push(Engine::Regex);
+    }
+    engines
+}
+
+fn matcher(pattern: &str, engine: Engine) -> Matcher {
+    let options = CompileOptions {
+        engine,
+        ..CompileOptions::default()
+    };
+    compile_matcher(pattern, &options).unwrap()
+}
+
+#[test]
+fn shortcuts_do_not_allocate() {
+    for engine in engines() {
+        let cases = [
+            ("*.rs", Candidate::new("src/lib.ts")),
+            ("**/*.md", Candidate::new("docs/index.html")),
+            ("src/lib.rs", Candidate::new("src/lib.rs")),
+            ("build/", Candidate::with_dir_hint("build", false)),
+            ("*.rs", Candidate::new("")),
+        ];
+        for (pattern, candidate) in cases {
+            let matcher = matcher(pattern, engine);
+            assert_eq!(
+                allocations(|| matcher.is_match_candidate(&candidate).unwrap()),
+                0,
+                "{:?} against {pattern:?} on {engine:?}",
+                candidate.path()
+            );
+        }
+    }
+}
+
+#[test]
+fn candidates_only_allocate_inside_the_engine() {
+    let patterns = [
+        "*.rs",
+        "**/*.rs",
+        "src/**",
+        "!*.rs",
+        "*.{rs,toml}",
+        "build/",
+    ];
+    let inputs = ["src/lib.rs", "a.ts", "src/a/b.rs", "build", "Cargo.toml"];
+
+    for engine in engines() {
+        for pattern in patterns {
+            let matcher = matcher(pattern, engine);
+            for input in inputs {
+                let candidate = Candidate::new(input);
+                assert!(
+                    allocations(|| matcher.is_match_candidate(&candidate).unwrap())
+                        <= allocations(|| matcher.is_match(input).unwrap()),
+                    "{input:?} against {pattern:?} on {engine:?}"
+                );
+
+                // A directory is tried bare and then with a trailing slash,
+                // whose spelling the candidate has already built.
+                let slashed = format!("{input}/");
+                let candidate = Candidate::with_dir_hint(input, true);
+                assert!(
+                    allocations(|| matcher.is_match_candidate(&candidate).unwrap())
+                        <= allocations(|| matcher.is_match(input).unwrap())
+                            + allocations(|| matcher.is_match(&slashed).unwrap()),
+                    "{input:?} (is_dir: true) against {pattern:?} on {engine:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use picomatch_rs::{is_match, max_depth, CompileOptions, Depth};
+
+fn depth(pattern: &str) -> Depth {
+    max_depth(pattern, &CompileOptions::default())
+}
+
+#[test]
+fn counts_segments_of_bounded_globs() {
+    assert_eq!(depth("*.js"), Depth::Finite(1));
+    assert_eq!(depth("src/*.js"), Depth::Finite(2));
+    assert_eq!(depth("./src/lib/"), Depth::Finite(2));
+    assert_eq!(depth("src/*/b/*.js"), Depth::Finite(4));
+    assert_eq!(depth("src/[/]/*.js"), Depth::Finite(3));
+}
+
+#[test]
+fn takes_the_deepest_brace_or_extglob_branch() {
+    assert_eq!(depth("{a,b/c/d}/*.js"), Depth::Finite(4));
+    assert_eq!(depth("src/@(x|y/z)/*.ts"), Depth::Finite(4));
+    assert_eq!(depth("file{1..3}.txt"), Depth::Finite(1));
+}
+
+#[test]
+fn globstars_are_unbounded() {
+    assert_eq!(depth("**"), Depth::Unbounded);
+    assert_eq!(depth("src/**/*.js"), Depth::Unbounded);
+    assert_eq!(depth("{**/a,b}"), Depth::Unbounded);
+    assert!(depth("a/**").is_unbounded());
+    assert_eq!(depth("a**/b"), Depth::Finite(2));
+
+    let noglobstar = CompileOptions {
+        noglobstar: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(max_depth("src/**/*.js", &noglobstar), Depth::Finite(3));
+}
+
+#[test]
+fn negations_and_basename_matching_are_unbounded() {
+    assert_eq!(depth("!src/*.js"), Depth::Unbounded);
+
+    let match_base = CompileOptions {
+        match_base: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(max_depth("*.js", &match_base), Depth::Unbounded);
+    assert_eq!(max_depth("src/*.js", &match_base), Depth::Finite(2));
+}
+
+#[test]
+fn bash_and_contains_are_unbounded() {
+    for options in [
+        CompileOptions {
+            bash: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            contains: true,
+            ..CompileOptions::default()
+        },
+    ] {
+        assert_eq!(max_depth("src/*.js", &options), Depth::Unbounded);
+        assert_eq!(max_depth("*.js", &options), Depth::Unbounded);
+    }
+
+    let bash = CompileOptions {
+        bash: true,
+        ..CompileOptions::default()
+    };
+    assert!(is_match("src/a/b/c.js", "src/*.js", &bash).unwrap());
+    let contains = CompileOptions {
+        contains: true,
+        ..CompileOptions::default()
+    };
+    assert!(is_match("x/y/src/a.js", "src/*.js", &contains).unwrap());
+}
+
+#[test]
+fn orders_and_adds_depths() {
+    assert!(Depth::Finite(usize::MAX) < Depth::Unbounded);
+    assert_eq!(Depth::Finite(1) + Depth::Finite(2), Depth::Finite(3));
+    assert_eq!(Depth::Finite(1) + Depth::Unbounded, Depth::Unbounded);
+    assert_eq!(Depth::Unbounded.as_f64(), f64::INFINITY);
+}
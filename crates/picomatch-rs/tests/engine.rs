@@ -0,0 +1,185 @@
+use picomatch_rs::{compile_matcher, CompileOptions, Engine, MatchError};
+
+#[cfg(feature = "regex")]
+const PATTERNS: [&str; 32] = [
+    "*",
+    "*.js",
+    "**",
+    "**/*.rs",
+    "a/**/b",
+    "a/**",
+    "**/",
+    "a/*/c",
+    "?",
+    "a?c",
+    "[abc]",
+    "[!a-c]*",
+    "[[:alpha:]][[:digit:]]",
+    ".*",
+    "**/.*",
+    "{a,b}/*.{js,ts}",
+    "{1..3}.txt",
+    "!*.js",
+    "!(*.js)",
+    "+(a|b)",
+    "*(foo)bar",
+    "@(a|b)/?(c)",
+    "a/!(b)/c",
+    "a/**/*/b",
+    "a\\*b",
+    "**/node_modules/**",
+    "src/**/!(*.test).ts",
+    "a/./b",
+    "*/",
+    "a**b",
+    "***",
+    "(a|b)*",
+];
+
+#[cfg(feature = "regex")]
+const INPUTS: [&str; 32] = [
+    "",
+    "a",
+    "b",
+    "a.js",
+    ".js",
+    "a/b",
+    "a/b/c",
+    "a/x/y/b",
+    "a/.b/c",
+    "a/../b",
+    "a/./b",
+    "abc",
+    "aXc",
+    "d",
+    "A1",
+    "a1",
+    ".env",
+    "x/.env",
+    "a/b.ts",
+    "b/c.js",
+    "2.txt",
+    "foobar",
+    "foofoobar",
+    "aab",
+    "a/c",
+    "a*b",
+    "node_modules/x/y",
+    "src/a/b.test.ts",
+    "src/a/b.ts",
+    "a/",
+    "a\nb",
+    "ñ/é.js",
+];
+
+#[cfg(feature = "regex")]
+fn option_variants() -> Vec<CompileOptions> {
+    vec![
+        CompileOptions::default(),
+        CompileOptions {
+            dot: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            nocase: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            windows: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            strict_slashes: true,
+            noglobstar: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            bash: true,
+            noextglob: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            match_base: true,
+            nobrace: true,
+            ..CompileOptions::default()
+        },
+    ]
+}
+
+#[cfg(feature = "regex")]
+fn with_engine(options: &CompileOptions, engine: Engine) -> CompileOptions {
+    CompileOptions {
+        engine,
+        ..options.clone()
+    }
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn glob_engine_agrees_with_regex_engine() {
+    for options in option_variants() {
+        for pattern in PATTERNS {
+            let regex = compile_matcher(pattern, &with_engine(&options, Engine::Regex)).unwrap();
+            let glob = compile_matcher(pattern, &with_engine(&options, Engine::Glob))
+                .unwrap_or_else(|err| panic!("{pattern:?} with {options:?}: {err:?}"));
+            assert_eq!(glob.engine(), Engine::Glob);
+
+            for input in INPUTS {
+                assert_eq!(
+                    glob.is_match(input).unwrap(),
+                    regex.is_match(input).unwrap(),
+                    "{input:?} against {pattern:?} with {options:?}"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn auto_uses_the_regex_engine_when_built_in() {
+    let options = CompileOptions::default();
+    for pattern in ["src/**/*.rs", "a\\d", "a[\\w]+c", "foo/*(?<!d)"] {
+        let matcher = compile_matcher(pattern, &options).unwrap();
+        assert_eq!(matcher.engine(), Engine::Regex, "{pattern:?}");
+    }
+    assert!(compile_matcher("a\\d", &options)
+        .unwrap()
+        .is_match("a1")
+        .unwrap());
+}
+
+#[cfg(not(feature = "regex"))]
+#[test]
+fn auto_uses_the_glob_engine_without_regex() {
+    let matcher = compile_matcher("src/**/*.rs", &CompileOptions::default()).unwrap();
+    assert_eq!(matcher.engine(), Engine::Glob);
+    assert!(matcher.is_match("src/a/b.rs").unwrap());
+}
+
+#[test]
+fn glob_engine_rejects_regex_features() {
+    let options = CompileOptions {
+        engine: Engine::Glob,
+        ..CompileOptions::default()
+    };
+    let result = compile_matcher("a\\d", &options);
+    assert!(
+        matches!(result, Err(MatchError::UnsupportedPattern(_))),
+        "expected glob engine to reject regex escapes, got: {result:?}"
+    );
+}
+
+#[test]
+fn glob_engine_does_not_backtrack() {
+    let options = CompileOptions {
+        engine: Engine::Glob,
+        ..CompileOptions::default()
+    };
+    let input = "a".repeat(20_000);
+
+    for pattern in ["*a*a*a*a*a*a*b", "**/*a*a*a*a*b", "+(a|aa)+(a|aa)b"] {
+        let matcher = compile_matcher(pattern, &options).unwrap();
+        assert!(!matcher.is_match(&input).unwrap(), "{pattern:?}");
+    }
+}
@@ -0,0 +1,142 @@
+use picomatch_rs::{
+    compile_matcher, escape_glob, escape_glob_windows, unescape_glob, CompileOptions,
+};
+
+const NAMES: &[&str] = &[
+    "app/page[id].tsx",
+    "app/[...slug]/page.tsx",
+    "app/(group)/layout.tsx",
+    "!important.md",
+    "a!(b).js",
+    "+(x).txt",
+    "@(scope)/pkg",
+    "{a,b}.js",
+    "notes?.txt",
+    "star*.txt",
+    "a|b",
+    "a+b@c!d.txt",
+    "$HOME/^x$.md",
+    "**/literal",
+    "dir/[!a]/x",
+];
+
+#[test]
+fn escaped_paths_match_only_themselves() {
+    let options = CompileOptions::default();
+    for name in NAMES {
+        let glob = escape_glob(name);
+        let matcher = compile_matcher(&glob, &options).unwrap();
+        assert!(
+            matcher.is_match(name).unwrap(),
+            "{glob} should match {name}"
+        );
+        for other in NAMES.iter().filter(|other| *other != name) {
+            assert!(
+                !matcher.is_match(other).unwrap(),
+                "{glob} should not match {other}"
+            );
+        }
+    }
+}
+
+#[test]
+fn quotes_and_backslash_runs_survive_escaping() {
+    let options = CompileOptions::default();
+    for name in [
+        "My \"Doc\" (1).txt",
+        "it's [draft].md",
+        "a\\\\b*",
+        "a\\\\\\b?",
+        "a\\\\\\\\+(x)",
+        "\\\\+[x]",
+        "\"*\"/'?'\\\\",
+        "dir//\\[\\]",
+    ] {
+        let glob = escape_glob(name);
+        assert_ne!(glob, name);
+        let matcher = compile_matcher(&glob, &options).unwrap();
+        assert!(
+            matcher.is_match(name).unwrap(),
+            "{glob} should match {name}"
+        );
+        assert_eq!(unescape_glob(&glob), name);
+    }
+}
+
+#[test]
+fn generated_names_match_their_escaped_globs() {
+    let alphabet = "ab./\\\"'*?[]{}()!+@|,^$ -#".chars().collect::<Vec<_>>();
+    let options = CompileOptions::default();
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize
+    };
+
+    for _ in 0..5_000 {
+        let len = 1 + random() % 10;
+        let name = (0..len)
+            .map(|_| alphabet[random() % alphabet.len()])
+            .collect::<String>();
+        if name
+            .split('/')
+            .any(|segment| segment == "." || segment == "..")
+        {
+            continue;
+        }
+        let glob = escape_glob(&name);
+        let matcher = compile_matcher(&glob, &options).unwrap();
+        assert!(
+            matcher.is_match(&name).unwrap(),
+            "{glob} should match {name}"
+        );
+        assert_eq!(unescape_glob(&glob), name);
+    }
+}
+
+#[test]
+fn escapes_only_what_is_special() {
+    assert_eq!(escape_glob("app/page[id].tsx"), "app/page\\[id\\].tsx");
+    assert_eq!(escape_glob("!a/b!c"), "\\!a/b!c");
+    assert_eq!(escape_glob("a+b@c+(d)"), "a+b@c\\+\\(d\\)");
+    assert_eq!(escape_glob("a\\b"), "a\\\\b");
+    assert_eq!(escape_glob("a\\\\b"), "a\\\\[\\\\]b");
+    assert_eq!(escape_glob("\"a's\""), "\\\"a\\'s\\\"");
+    assert_eq!(escape_glob("plain/path.rs"), "plain/path.rs");
+}
+
+#[test]
+fn backslashes_are_literal_in_posix_mode() {
+    let matcher = compile_matcher(&escape_glob("a\\*b"), &CompileOptions::default()).unwrap();
+    assert!(matcher.is_match("a\\*b").unwrap());
+    assert!(!matcher.is_match("a\\xb").unwrap());
+}
+
+#[test]
+fn windows_paths_keep_backslashes_as_separators() {
+    assert_eq!(
+        escape_glob_windows("app\\page[id].tsx"),
+        "app/page\\[id\\].tsx"
+    );
+
+    let options = CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    };
+    let matcher =
+        compile_matcher(&escape_glob_windows("app\\(group)\\[id].tsx"), &options).unwrap();
+    assert!(matcher.is_match("app\\(group)\\[id].tsx").unwrap());
+    assert!(matcher.is_match("app/(group)/[id].tsx").unwrap());
+    assert!(!matcher.is_match("app\\group\\i.tsx").unwrap());
+}
+
+#[test]
+fn unescape_inverts_escape() {
+    for name in NAMES {
+        assert_eq!(unescape_glob(&escape_glob(name)), *name);
+    }
+    assert_eq!(unescape_glob("a\\\\b"), "a\\b");
+    assert_eq!(unescape_glob("page\\[id\\].tsx"), "page[id].tsx");
+}
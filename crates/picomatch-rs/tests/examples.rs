@@ -0,0 +1,117 @@
+use picomatch_rs::{compile_matcher, CompileOptions, Examples};
+
+fn examples(pattern: &str, count: usize, options: &CompileOptions) -> Examples {
+    compile_matcher(pattern, options)
+        .unwrap()
+        .examples(count)
+        .unwrap()
+}
+
+fn assert_verified(pattern: &str, examples: &Examples, options: &CompileOptions) {
+    let matcher = compile_matcher(pattern, options).unwrap();
+    for path in &examples.matches {
+        assert!(
+            matcher.is_match(path).unwrap(),
+            "{path} should match {pattern}"
+        );
+    }
+    for path in &examples.near_misses {
+        assert!(
+            !matcher.is_match(path).unwrap(),
+            "{path} should not match {pattern}"
+        );
+    }
+}
+
+#[test]
+fn spells_out_globstars_from_shallow_to_deep() {
+    let options = CompileOptions::default();
+    let examples = examples("src/**/*.ts", 8, &options);
+    assert_verified("src/**/*.ts", &examples, &options);
+
+    assert_eq!(examples.matches[0], "src/a.ts");
+    assert!(examples.matches.contains(&"src/a/a.ts".to_string()));
+    assert!(examples.matches.contains(&"src/a/b/c/a.ts".to_string()));
+    assert!(examples.near_misses.contains(&"src/.a/a.ts".to_string()));
+    assert!(examples.near_misses.contains(&"src//a.ts".to_string()));
+    assert!(examples.near_misses.contains(&"src/.a.ts".to_string()));
+}
+
+#[test]
+fn dotfiles_follow_the_dot_option() {
+    let examples_without = examples("*.js", 8, &CompileOptions::default());
+    assert!(examples_without.near_misses.contains(&".a.js".to_string()));
+
+    let options = CompileOptions {
+        dot: true,
+        ..CompileOptions::default()
+    };
+    let examples_with = examples("*.js", 8, &options);
+    assert_verified("*.js", &examples_with, &options);
+    assert!(examples_with.matches.contains(&".a.js".to_string()));
+}
+
+#[test]
+fn expands_braces_and_ranges() {
+    let options = CompileOptions::default();
+    let braces = examples("*.{js,ts}", 8, &options);
+    assert_verified("*.{js,ts}", &braces, &options);
+    assert!(braces.matches.contains(&"a.js".to_string()));
+    assert!(braces.matches.contains(&"a.ts".to_string()));
+
+    let range = examples("file{1..3}.txt", 8, &options);
+    assert_verified("file{1..3}.txt", &range, &options);
+    assert_eq!(range.matches, ["file1.txt", "file3.txt", "file2.txt"]);
+    assert_eq!(range.near_misses[0], "file4.txt");
+}
+
+#[test]
+fn picks_members_of_posix_and_negated_classes() {
+    let options = CompileOptions::default();
+    let digits = examples("v[[:digit:]].md", 4, &options);
+    assert_verified("v[[:digit:]].md", &digits, &options);
+    assert_eq!(digits.matches, ["v0.md", "v9.md"]);
+
+    let others = examples("v[!0-9].md", 4, &options);
+    assert_verified("v[!0-9].md", &others, &options);
+    assert_eq!(others.matches[0], "va.md");
+    assert!(others.near_misses.contains(&"v0.md".to_string()));
+}
+
+#[test]
+fn covers_extglobs() {
+    let options = CompileOptions::default();
+    for pattern in ["+(a|b)c", "!(foo).js", "@(x|y)/**/z", "*(ab)!"] {
+        let examples = examples(pattern, 6, &options);
+        assert!(!examples.matches.is_empty(), "{pattern}");
+        assert_verified(pattern, &examples, &options);
+    }
+
+    let negated = examples("!(foo).js", 6, &options);
+    assert!(negated.near_misses.contains(&"foo.js".to_string()));
+}
+
+#[test]
+fn inverts_negated_globs() {
+    let options = CompileOptions::default();
+    let examples = examples("!*.js", 4, &options);
+    assert_verified("!*.js", &examples, &options);
+    assert!(examples.near_misses.contains(&"a.js".to_string()));
+}
+
+#[test]
+fn respects_the_count() {
+    let options = CompileOptions::default();
+    let examples = examples("**/*.rs", 2, &options);
+    assert_eq!(examples.matches.len(), 2);
+    assert_eq!(examples.near_misses.len(), 2);
+    assert_eq!(self::examples("*", 0, &options), Examples::default());
+}
+
+#[test]
+fn literal_globs_match_themselves() {
+    let options = CompileOptions::default();
+    let examples = examples("foo.txt", 4, &options);
+    assert_eq!(examples.matches, ["foo.txt"]);
+    assert_verified("foo.txt", &examples, &options);
+}
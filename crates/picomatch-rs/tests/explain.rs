@@ -0,0 +1,218 @@
+use picomatch_rs::{compile_matcher, CompileOptions, ExplainStep, Explanation};
+
+fn explain(pattern: &str, input: &str, options: &CompileOptions) -> Explanation {
+    compile_matcher(pattern, options)
+        .unwrap()
+        .explain(input)
+        .unwrap()
+}
+
+fn lines(explanation: &Explanation) -> Vec<String> {
+    explanation.steps.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn reports_the_failing_segment() {
+    let explanation = explain("src/*.ts", "src/index.tsx", &CompileOptions::default());
+    assert!(!explanation.is_match);
+    assert_eq!(
+        explanation.steps,
+        [
+            ExplainStep::Matched {
+                index: 0,
+                glob: "src".to_string(),
+                input: "src".to_string(),
+            },
+            ExplainStep::Mismatch {
+                index: 1,
+                glob: "*.ts".to_string(),
+                input: "index.tsx".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        explanation.to_string(),
+        "`src/index.tsx` does not match `src/*.ts`\n  \
+         - segment 1 `src` matched `src`\n  \
+         - segment 2 `*.ts` did not match `index.tsx`"
+    );
+}
+
+#[test]
+fn shows_what_globstars_consumed() {
+    let options = CompileOptions::default();
+    let explanation = explain("src/**/*.rs", "src/a/b/lib.rs", &options);
+    assert!(explanation.is_match);
+    assert_eq!(
+        lines(&explanation),
+        [
+            "segment 1 `src` matched `src`",
+            "segment 2 `**` matched `a/b`",
+            "segment 3 `*.rs` matched `lib.rs`",
+        ]
+    );
+
+    assert_eq!(
+        lines(&explain("src/**", "src", &options)),
+        [
+            "segment 1 `src` matched `src`",
+            "segment 2 `**` matched nothing"
+        ]
+    );
+}
+
+#[test]
+fn reports_missing_and_extra_segments() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        lines(&explain("a/*/c", "a/b", &options)).last().unwrap(),
+        "segment 3 `c` had no input left to match"
+    );
+    assert_eq!(
+        lines(&explain("a/b", "a/b/c/d", &options)).last().unwrap(),
+        "`c/d` was left over after the last segment"
+    );
+}
+
+#[test]
+fn hints_at_dot_and_case_options() {
+    let options = CompileOptions::default();
+    let explanation = explain("**/*.js", "x/.cache/a.js", &options);
+    assert!(explanation.steps.contains(&ExplainStep::Dotfile {
+        input: ".cache".to_string()
+    }));
+    assert!(lines(&explanation)
+        .contains(&"dotfile `.cache` excluded because `dot` is false".to_string()));
+
+    let explanation = explain("*.md", "README.MD", &options);
+    assert_eq!(
+        explanation.steps.last(),
+        Some(&ExplainStep::Case {
+            input: "README.MD".to_string()
+        })
+    );
+
+    let dot = CompileOptions {
+        dot: true,
+        ..CompileOptions::default()
+    };
+    assert!(explain("**/*.js", "x/.cache/a.js", &dot).is_match);
+    assert!(!explain("*.js", ".js/a.ts", &options)
+        .steps
+        .iter()
+        .any(|step| matches!(step, ExplainStep::Dotfile { .. })));
+}
+
+#[test]
+fn explains_negation_and_match_base() {
+    let options = CompileOptions::default();
+    let explanation = explain("!**/*.test.ts", "src/a.test.ts", &options);
+    assert!(!explanation.is_match);
+    assert_eq!(
+        explanation.steps.last(),
+        Some(&ExplainStep::Negated { matched: true })
+    );
+    assert!(explanation.to_string().ends_with(
+        "negated by leading `!`: the rest of the pattern matched, so the input is rejected"
+    ));
+    assert!(explain("!**/*.test.ts", "src/a.ts", &options).is_match);
+
+    let options = CompileOptions {
+        match_base: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(
+        lines(&explain("*.rs", "src/lib.rs", &options)),
+        [
+            "matching basename `lib.rs` because `match_base` is set",
+            "segment 1 `*.rs` matched `lib.rs`",
+        ]
+    );
+}
+
+#[test]
+fn falls_back_to_the_whole_pattern() {
+    let explanation = explain("a/{b/c,d}/e", "a/b/c/e", &CompileOptions::default());
+    assert!(explanation.is_match);
+    assert_eq!(
+        explanation.steps,
+        [ExplainStep::Whole {
+            glob: "a/{b/c,d}/e".to_string(),
+            input: "a/b/c/e".to_string(),
+            matched: true,
+        }]
+    );
+}
+
+#[test]
+fn verdict_always_agrees_with_is_match() {
+    let patterns = [
+        "*",
+        "**",
+        "*.js",
+        "**/*.js",
+        "a/**/b",
+        "a/*/c",
+        "a/**",
+        "!a/*",
+        "!(*.js)",
+        "a/{b,c}/d",
+        "{a,b}/**/*.{js,ts}",
+        "a/[bc]/d",
+        "/a/*",
+        "a/*/",
+        ".*",
+        "a/!(b)/c",
+        "**/.*",
+        "a/b/**/",
+    ];
+    let inputs = [
+        "a", "a.js", ".a.js", "a/b", "a/b/", "a/b/c", "a/x/y/b", "a/c/d", "/a/b", "b/x/y.ts",
+        "a/.b/c", ".env", "x/.env", "a/b/c/d/", "A.JS", "a/../b",
+    ];
+    let variants = [
+        CompileOptions::default(),
+        CompileOptions {
+            dot: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            match_base: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            strict_slashes: true,
+            windows: true,
+            ..CompileOptions::default()
+        },
+    ];
+
+    for options in &variants {
+        for pattern in patterns {
+            let matcher = compile_matcher(pattern, options).unwrap();
+            let explainer = matcher.explainer();
+            for input in inputs {
+                let explanation = explainer.explain(input).unwrap();
+                assert_eq!(
+                    explanation.is_match,
+                    matcher.is_match(input).unwrap(),
+                    "{input:?} against {pattern:?} with {options:?}"
+                );
+                assert!(!explanation.steps.is_empty());
+            }
+        }
+    }
+}
+
+#[test]
+fn explainer_reuses_matchers_across_inputs() {
+    let matcher = compile_matcher("src/**/*.rs", &CompileOptions::default()).unwrap();
+    let explainer = matcher.explainer();
+    for input in ["src/lib.rs", "src/a/.b/c.rs", "SRC/lib.rs", "src/lib.ts", "src"] {
+        assert_eq!(
+            explainer.explain(input).unwrap(),
+            matcher.explain(input).unwrap(),
+            "{input:?}"
+        );
+    }
+}
@@ -2,7 +2,7 @@ mod support;
 
 use picomatch_rs::CompileOptions;
 
-use support::{assert_is_match, default_compile_options};
+use support::assert_is_match;
 
 fn bash_options(windows: bool) -> CompileOptions {
     CompileOptions {
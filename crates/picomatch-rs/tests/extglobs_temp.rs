@@ -1,6 +1,6 @@
 mod support;
 use picomatch_rs::CompileOptions;
-use support::{assert_is_match, default_compile_options};
+use support::assert_is_match;
 
 fn windows_options() -> CompileOptions {
     CompileOptions {
@@ -9,13 +9,6 @@ fn windows_options() -> CompileOptions {
     }
 }
 
-fn posix_options() -> CompileOptions {
-    CompileOptions {
-        posix: true,
-        ..CompileOptions::default()
-    }
-}
-
 #[allow(unused_imports)]
 #[cfg(test)]
 mod tests {
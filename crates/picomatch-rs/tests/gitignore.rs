@@ -0,0 +1,150 @@
+use picomatch_rs::{GitignoreMatch, GitignoreMatcher};
+
+fn gitignore(contents: &str) -> GitignoreMatcher {
+    GitignoreMatcher::parse("", contents).expect("gitignore should parse")
+}
+
+fn assert_ignored(matcher: &GitignoreMatcher, path: &str, is_dir: bool, expected: bool) {
+    let actual = matcher
+        .is_ignored(path, is_dir)
+        .unwrap_or_else(|err| panic!("is_ignored({path:?}) failed: {err:?}"));
+    assert_eq!(
+        actual, expected,
+        "unexpected ignore result for {path:?} (is_dir: {is_dir})"
+    );
+}
+
+#[test]
+fn skips_blank_lines_and_comments() {
+    let matcher = gitignore("\n# comment\n   \n#*.rs\n");
+    assert!(matcher.is_empty());
+    assert_ignored(&matcher, "main.rs", false, false);
+}
+
+#[test]
+fn unanchored_patterns_match_at_any_depth() {
+    let matcher = gitignore("*.log\nbuild\n");
+    assert_ignored(&matcher, "debug.log", false, true);
+    assert_ignored(&matcher, "a/b/debug.log", false, true);
+    assert_ignored(&matcher, ".hidden.log", false, true);
+    assert_ignored(&matcher, "build", true, true);
+    assert_ignored(&matcher, "src/build", false, true);
+    assert_ignored(&matcher, "src/build/out.js", false, true);
+    assert_ignored(&matcher, "src/builder", true, false);
+    assert_ignored(&matcher, "debug.log.txt", false, false);
+}
+
+#[test]
+fn leading_and_middle_slashes_anchor_patterns() {
+    let matcher = gitignore("/target\ndoc/*.html\n");
+    assert_ignored(&matcher, "target", true, true);
+    assert_ignored(&matcher, "crates/target", true, false);
+    assert_ignored(&matcher, "doc/index.html", false, true);
+    assert_ignored(&matcher, "doc/api/index.html", false, false);
+    assert_ignored(&matcher, "src/doc/index.html", false, false);
+}
+
+#[test]
+fn trailing_slash_only_matches_directories() {
+    let matcher = gitignore("node_modules/\nlogs/\n");
+    assert_ignored(&matcher, "node_modules", true, true);
+    assert_ignored(&matcher, "node_modules", false, false);
+    assert_ignored(&matcher, "pkg/node_modules", true, true);
+    assert_ignored(&matcher, "node_modules/left-pad/index.js", false, true);
+    assert_ignored(&matcher, "logs", false, false);
+    assert_ignored(&matcher, "logs/today.txt", false, true);
+}
+
+#[test]
+fn globstar_segments_follow_git_rules() {
+    let matcher = gitignore("**/foo\nabc/**\na/**/b\n");
+    assert_ignored(&matcher, "foo", false, true);
+    assert_ignored(&matcher, "x/y/foo", false, true);
+    assert_ignored(&matcher, "abc", true, false);
+    assert_ignored(&matcher, "abc/x", false, true);
+    assert_ignored(&matcher, "abc/x/y", false, true);
+    assert_ignored(&matcher, "a/b", false, true);
+    assert_ignored(&matcher, "a/x/y/b", false, true);
+    assert_ignored(&matcher, "x/a/b", false, false);
+}
+
+#[test]
+fn negation_uses_last_match() {
+    let matcher = gitignore("*.log\n!important.log\nimportant.log.d/\n");
+    assert_ignored(&matcher, "debug.log", false, true);
+    assert_ignored(&matcher, "important.log", false, false);
+    assert_eq!(
+        matcher.matched("important.log", false).unwrap(),
+        GitignoreMatch::Whitelist
+    );
+    assert_eq!(
+        matcher.matched("README.md", false).unwrap(),
+        GitignoreMatch::None
+    );
+
+    let matcher = gitignore("!important.log\n*.log\n");
+    assert_ignored(&matcher, "important.log", false, true);
+}
+
+#[test]
+fn cannot_reinclude_files_below_an_excluded_directory() {
+    let matcher = gitignore("build/\n!build/keep.txt\n");
+    assert_ignored(&matcher, "build/keep.txt", false, true);
+
+    let matcher = gitignore("build/*\n!build/keep.txt\n");
+    assert_ignored(&matcher, "build/keep.txt", false, false);
+    assert_ignored(&matcher, "build/other.txt", false, true);
+
+    let matcher = gitignore("abc/**\n!abc/keep\n");
+    assert_ignored(&matcher, "abc/keep", false, false);
+    assert_ignored(&matcher, "abc/drop", false, true);
+}
+
+#[test]
+fn supports_escaped_hash_bang_and_trailing_spaces() {
+    let matcher = gitignore("\\#notes\n\\!bang\ntrailing   \nspace\\ \n");
+    assert_ignored(&matcher, "#notes", false, true);
+    assert_ignored(&matcher, "!bang", false, true);
+    assert_ignored(&matcher, "trailing", false, true);
+    assert_ignored(&matcher, "trailing   ", false, false);
+    assert_ignored(&matcher, "space ", false, true);
+    assert_ignored(&matcher, "space", false, false);
+}
+
+#[test]
+fn treats_other_glob_syntax_literally() {
+    let matcher = gitignore("{a,b}.txt\n+(x)\n@scope\nfile(1).txt\n");
+    assert_ignored(&matcher, "{a,b}.txt", false, true);
+    assert_ignored(&matcher, "a.txt", false, false);
+    assert_ignored(&matcher, "+(x)", false, true);
+    assert_ignored(&matcher, "x", false, false);
+    assert_ignored(&matcher, "@scope", false, true);
+    assert_ignored(&matcher, "file(1).txt", false, true);
+}
+
+#[test]
+fn supports_bracket_expressions() {
+    let matcher = gitignore("*.py[co]\nlog[!s]\n[[:digit:]]*.tmp\nunclosed[\n");
+    assert_ignored(&matcher, "mod.pyc", false, true);
+    assert_ignored(&matcher, "mod.pyo", false, true);
+    assert_ignored(&matcher, "mod.py", false, false);
+    assert_ignored(&matcher, "mod.py[co]", false, false);
+    assert_ignored(&matcher, "log1", false, true);
+    assert_ignored(&matcher, "logs", false, false);
+    assert_ignored(&matcher, "1file.tmp", false, true);
+    assert_ignored(&matcher, "file.tmp", false, false);
+    assert_ignored(&matcher, "unclosed[", false, false);
+    assert_eq!(matcher.rules().len(), 3);
+}
+
+#[test]
+fn nested_gitignore_is_relative_to_its_directory() {
+    let matcher = GitignoreMatcher::parse("packages/app", "/dist\n*.tmp\n").unwrap();
+    assert_eq!(matcher.root(), "packages/app");
+    assert_ignored(&matcher, "packages/app/dist", true, true);
+    assert_ignored(&matcher, "packages/app/src/dist", true, false);
+    assert_ignored(&matcher, "packages/app/src/a.tmp", false, true);
+    assert_ignored(&matcher, "dist", true, false);
+    assert_ignored(&matcher, "packages/other/a.tmp", false, false);
+    assert_ignored(&matcher, "packages/app", true, false);
+}
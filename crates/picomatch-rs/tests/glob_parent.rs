@@ -0,0 +1,59 @@
+use picomatch_rs::{glob_parent, GlobParentOptions};
+
+fn parent(input: &str) -> String {
+    glob_parent(input, &GlobParentOptions::default())
+}
+
+#[test]
+fn strips_glob_segments() {
+    assert_eq!(parent("path/to/*.js"), "path/to");
+    assert_eq!(parent("/root/path/to/*.js"), "/root/path/to");
+    assert_eq!(parent("/*.js"), "/");
+    assert_eq!(parent("*.js"), ".");
+    assert_eq!(parent("**/*.js"), ".");
+    assert_eq!(parent("path/**/*"), "path");
+    assert_eq!(parent("path/[a-z]/*.js"), "path");
+    assert_eq!(parent("path/{to,from}"), "path");
+    assert_eq!(parent("path/foo/(bar|baz)"), "path/foo");
+    for extglob in ["!", "?", "+", "*", "@"] {
+        assert_eq!(parent(&format!("path/{extglob}(to|from)")), "path");
+    }
+}
+
+#[test]
+fn keeps_trailing_directories() {
+    assert_eq!(parent("path/foo/bar.js"), "path/foo");
+    assert_eq!(parent("path/foo/"), "path/foo");
+    assert_eq!(parent("path/foo"), "path");
+    assert_eq!(parent("path"), ".");
+    assert_eq!(parent(""), ".");
+    assert_eq!(parent("/"), "/");
+}
+
+#[test]
+fn unescapes_escaped_glob_characters() {
+    assert_eq!(parent("path/\\*\\*/subdir/foo.*"), "path/**/subdir");
+    assert_eq!(parent("path/\\[bar]/"), "path/[bar]");
+    assert_eq!(parent("path/\\{foo,bar\\}/"), "path/{foo,bar}");
+    assert_eq!(parent("path/\\(a|b\\)/*.js"), "path/(a|b)");
+    assert_eq!(parent("path/[bar]"), "path");
+    assert_eq!(parent("[bar]"), ".");
+    assert_eq!(parent("path/{foo,bar}/"), "path");
+}
+
+#[test]
+fn enclosures_holding_separators_are_one_segment() {
+    assert_eq!(parent("path/{,/,bar/baz,qux}"), "path");
+    assert_eq!(parent("path/[a/b]"), "path");
+    assert_eq!(parent("path/{a,b}/c"), "path");
+}
+
+#[cfg(not(windows))]
+#[test]
+fn backslashes_stay_escapes_outside_windows() {
+    assert_eq!(parent("path\\to\\*.js"), ".");
+    let options = GlobParentOptions {
+        flip_backslashes: false,
+    };
+    assert_eq!(glob_parent("path/to/*.js", &options), "path/to");
+}
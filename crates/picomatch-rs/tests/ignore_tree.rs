@@ -0,0 +1,161 @@
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use picomatch_rs::{GitignoreMatcher, IgnoreTree};
+
+struct Fixture {
+    root: PathBuf,
+}
+
+impl Fixture {
+    fn new(name: &str) -> Self {
+        let root =
+            std::env::temp_dir().join(format!("picomatch-rs-ignore-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Self { root }
+    }
+
+    fn file(&self, path: &str, contents: &str) -> &Self {
+        let path = self.root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+        self
+    }
+
+    fn path(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+#[test]
+fn deeper_ignore_files_take_precedence() {
+    let fixture = Fixture::new("precedence");
+    fixture
+        .file(".gitignore", "*.log\nbuild/\n")
+        .file("pkg/.gitignore", "!keep.log\n")
+        .file("pkg/sub/.gitignore", "keep.log\n");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert!(tree.is_ignored("debug.log", false).unwrap());
+    assert!(tree.is_ignored("pkg/debug.log", false).unwrap());
+    assert!(!tree.is_ignored("pkg/keep.log", false).unwrap());
+    assert!(!tree.is_ignored("pkg/other/keep.log", false).unwrap());
+    assert!(tree.is_ignored("pkg/sub/keep.log", false).unwrap());
+    assert!(tree.is_ignored("pkg/build", true).unwrap());
+    assert!(!tree.is_ignored("pkg/build", false).unwrap());
+    assert!(!tree.is_ignored("src/main.rs", false).unwrap());
+}
+
+#[test]
+fn nested_patterns_are_relative_to_their_directory() {
+    let fixture = Fixture::new("relative");
+    fixture.file("app/.gitignore", "/dist\n");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert!(tree.is_ignored("app/dist", true).unwrap());
+    assert!(tree.is_ignored("app/dist/index.js", false).unwrap());
+    assert!(!tree.is_ignored("app/src/dist", true).unwrap());
+    assert!(!tree.is_ignored("dist", true).unwrap());
+}
+
+#[test]
+fn excluded_directories_hide_nested_ignore_files() {
+    let fixture = Fixture::new("excluded");
+    fixture
+        .file(".gitignore", "vendor/\n")
+        .file("vendor/.gitignore", "!*\n")
+        .file("vendor/lib.rs", "");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert!(tree.is_ignored("vendor/lib.rs", false).unwrap());
+}
+
+#[test]
+fn later_file_names_take_precedence() {
+    let fixture = Fixture::new("file-names");
+    fixture
+        .file(".gitignore", "*.snap\n")
+        .file(".ignore", "!keep.snap\n")
+        .file(".prettierignore", "*.md\n");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert!(tree.is_ignored("a.snap", false).unwrap());
+    assert!(!tree.is_ignored("keep.snap", false).unwrap());
+    assert!(!tree.is_ignored("README.md", false).unwrap());
+
+    let mut tree = IgnoreTree::with_file_names(fixture.path(), [".prettierignore"]);
+    assert_eq!(tree.file_names(), [".prettierignore"]);
+    assert!(tree.is_ignored("README.md", false).unwrap());
+    assert!(!tree.is_ignored("a.snap", false).unwrap());
+}
+
+#[test]
+fn global_rules_have_the_lowest_precedence() {
+    let fixture = Fixture::new("global");
+    fixture.file(".gitignore", "!local.env\n");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    tree.add_global(GitignoreMatcher::parse("", "*.env\n").unwrap());
+    assert!(tree.is_ignored("prod.env", false).unwrap());
+    assert!(!tree.is_ignored("local.env", false).unwrap());
+}
+
+#[test]
+fn walks_non_ignored_files() {
+    let fixture = Fixture::new("walk");
+    fixture
+        .file(".gitignore", "target/\n*.tmp\n")
+        .file("src/lib.rs", "")
+        .file("src/scratch.tmp", "")
+        .file("src/nested/.ignore", "generated.rs\n")
+        .file("src/nested/generated.rs", "")
+        .file("src/nested/mod.rs", "")
+        .file("target/debug/app", "")
+        .file(".git/HEAD", "")
+        .file("README.md", "");
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert_eq!(
+        tree.walk().unwrap(),
+        [
+            ".gitignore",
+            "README.md",
+            "src/lib.rs",
+            "src/nested/.ignore",
+            "src/nested/mod.rs",
+        ]
+        .map(PathBuf::from)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn walks_non_utf8_names_unchanged() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let fixture = Fixture::new("walk-bytes");
+    fixture.file(".gitignore", "*.tmp\n");
+    let dir = fixture.path().join(OsStr::from_bytes(b"caf\xe9"));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(OsStr::from_bytes(b"\xff.rs")), "").unwrap();
+    fs::write(dir.join(OsStr::from_bytes(b"\xff.tmp")), "").unwrap();
+
+    let mut tree = IgnoreTree::new(fixture.path());
+    assert_eq!(
+        tree.walk().unwrap(),
+        [
+            PathBuf::from(".gitignore"),
+            PathBuf::from(OsStr::from_bytes(b"caf\xe9/\xff.rs")),
+        ]
+    );
+}
@@ -0,0 +1,92 @@
+use picomatch_rs::{compile_matcher, infer_globs, CompileOptions};
+
+fn infer(include: &[&str], exclude: &[&str]) -> Vec<String> {
+    let options = CompileOptions::default();
+    let globs = infer_globs(include, exclude, &options).unwrap().unwrap();
+    let matchers = globs
+        .iter()
+        .map(|glob| compile_matcher(glob, &options).unwrap())
+        .collect::<Vec<_>>();
+    for path in include {
+        assert!(
+            matchers
+                .iter()
+                .any(|matcher| matcher.is_match(path).unwrap()),
+            "{path} should be included by {globs:?}"
+        );
+    }
+    for path in exclude {
+        assert!(
+            !matchers
+                .iter()
+                .any(|matcher| matcher.is_match(path).unwrap()),
+            "{path} should be excluded by {globs:?}"
+        );
+    }
+    globs
+}
+
+#[test]
+fn generalizes_package_sources() {
+    let globs = infer(
+        &[
+            "packages/a/src/index.ts",
+            "packages/b/src/lib/util.ts",
+            "packages/c/src/x/y/z.ts",
+        ],
+        &["packages/a/test/index.ts", "packages/a/src/index.js"],
+    );
+    assert_eq!(globs, ["packages/*/src/**/*.ts"]);
+}
+
+#[test]
+fn prefers_aligned_directories_and_shared_extensions() {
+    assert_eq!(infer(&["src/a.ts", "src/b.ts"], &[]), ["src/*.ts"]);
+    assert_eq!(infer(&["a.test.ts", "b.test.ts"], &["c.ts"]), ["*.test.ts"]);
+    assert_eq!(
+        infer(&["a/src/x.ts", "b/c/src/y.ts"], &["a/lib/x.ts"]),
+        ["**/src/*.ts"]
+    );
+}
+
+#[test]
+fn lists_names_the_excludes_leave_apart() {
+    assert_eq!(
+        infer(&["src/a.ts", "src/b.ts"], &["src/c.ts"]),
+        ["src/{a.ts,b.ts}"]
+    );
+}
+
+#[test]
+fn splits_paths_that_cannot_share_a_glob() {
+    assert_eq!(
+        infer(&["src/a.ts", "lib/b.js"], &["src/b.js", "lib/a.ts"]),
+        ["lib/b.js", "src/a.ts"]
+    );
+}
+
+#[test]
+fn escapes_literal_names() {
+    assert_eq!(
+        infer(&["we[ird]/f(1).txt"], &["we/f1.txt"]),
+        [r"we\[ird\]/f\(1\).txt"]
+    );
+    assert_eq!(
+        infer(&["a,b.txt", "c.txt"], &["d.txt"]),
+        [r"{a\,b.txt,c.txt}"]
+    );
+    assert_eq!(
+        infer(&["docs/\"a\" (1).md"], &["docs/a 1.md"]),
+        [r#"docs/\"a\" \(1\).md"#]
+    );
+}
+
+#[test]
+fn reports_paths_both_included_and_excluded() {
+    let options = CompileOptions::default();
+    assert_eq!(infer_globs(&["a.ts"], &["a.ts"], &options).unwrap(), None);
+    assert_eq!(
+        infer_globs(&[], &["a.ts"], &options).unwrap(),
+        Some(Vec::new())
+    );
+}
@@ -0,0 +1,127 @@
+use picomatch_rs::{is_dynamic_pattern, is_extglob, is_glob, CompileOptions, IsGlobOptions};
+
+fn strict(input: &str) -> bool {
+    is_glob(input, &IsGlobOptions::default())
+}
+
+fn relaxed(input: &str) -> bool {
+    is_glob(input, &IsGlobOptions { strict: false })
+}
+
+#[test]
+fn plain_paths_are_not_globs() {
+    for input in ["", "abc.js", "abc/def/ghi.js", "abc/@.js", "abc/!.js"] {
+        assert!(!strict(input), "{input}");
+        assert!(!relaxed(input), "{input}");
+    }
+}
+
+#[test]
+fn recognizes_complete_glob_constructs() {
+    for input in [
+        "*.js",
+        "!*.js",
+        "!foo",
+        "**/abc.js",
+        "abc/[a-z].js",
+        "abc/{a,b}.js",
+        "abc/(aaa|bbb).js",
+        "abc/@(a|b).js",
+        "abc/+(a).js",
+        "abc/[a].?",
+        "(?:abc)",
+        "(?!abc)",
+    ] {
+        assert!(strict(input), "{input}");
+        assert!(relaxed(input), "{input}");
+    }
+}
+
+#[test]
+fn strict_mode_ignores_incomplete_constructs() {
+    for input in [
+        "abc/(a).js",
+        "abc/[].js",
+        "abc/{}.js",
+        "abc/?.js",
+        "abc/(a",
+        "a]b",
+    ] {
+        assert!(!strict(input), "{input}");
+        assert!(relaxed(input), "{input}");
+    }
+}
+
+#[test]
+fn escaped_characters_are_not_globs() {
+    for input in [
+        "abc/\\*.js",
+        "\\!foo",
+        "abc/\\[a-z].js",
+        "abc/\\{a,b}.js",
+        "abc/\\(a|b).js",
+    ] {
+        assert!(!is_extglob(input), "{input}");
+        assert!(!relaxed(input), "{input}");
+    }
+    assert!(!strict("abc/\\*.js"));
+    assert!(!strict("abc/\\[a-z].js"));
+    assert!(!strict("abc/\\{a,b}.js"));
+    assert!(!strict("abc/\\(a|b).js"));
+    // Only the extglob marker is escaped; the parentheses still count.
+    assert!(!is_extglob("abc/\\@(a|b).js"));
+    assert!(strict("abc/\\@(a|b).js"));
+}
+
+#[test]
+fn detects_extglobs() {
+    for input in [
+        "?(abc)",
+        "@(abc)",
+        "!(abc)",
+        "*(abc)",
+        "+(abc)",
+        "abc/!(def).js",
+    ] {
+        assert!(is_extglob(input), "{input}");
+    }
+    for input in ["abc", "(abc)", "@abc", "+(abc", "\\+(abc)", "@(abc\n)"] {
+        assert!(!is_extglob(input), "{input}");
+    }
+}
+
+#[test]
+fn dynamic_patterns_follow_fast_glob() {
+    let options = CompileOptions::default();
+    for pattern in [
+        "*", "a?c", "!abc", "[abc]", "(a|b)", "@(abc)", "{a,b}", "{1..3}", "abc\\def",
+    ] {
+        assert!(is_dynamic_pattern(pattern, &options), "{pattern}");
+    }
+    for pattern in ["", "abc", "a/b.js", "a(b)", "{a}", "[abc", "abc]"] {
+        assert!(!is_dynamic_pattern(pattern, &options), "{pattern}");
+    }
+}
+
+#[test]
+fn dynamic_patterns_respect_options() {
+    let nocase = CompileOptions {
+        nocase: true,
+        ..CompileOptions::default()
+    };
+    assert!(is_dynamic_pattern("abc", &nocase));
+
+    let noextglob = CompileOptions {
+        noextglob: true,
+        ..CompileOptions::default()
+    };
+    assert!(!is_dynamic_pattern("@(abc)", &noextglob));
+    assert!(!is_dynamic_pattern("@(a|b)", &noextglob));
+    assert!(is_dynamic_pattern("x(a|b)", &noextglob));
+
+    let nobrace = CompileOptions {
+        nobrace: true,
+        ..CompileOptions::default()
+    };
+    assert!(!is_dynamic_pattern("{a,b}", &nobrace));
+}
@@ -0,0 +1,131 @@
+use picomatch_rs::{compile_matcher, lint, CompileOptions, Diagnostic, DiagnosticKind};
+
+fn kinds(pattern: &str, options: &CompileOptions) -> Vec<(DiagnosticKind, usize, usize)> {
+    lint(pattern, options)
+        .iter()
+        .map(|diagnostic| (diagnostic.kind, diagnostic.start, diagnostic.end))
+        .collect()
+}
+
+#[test]
+fn accepts_well_formed_patterns() {
+    let options = CompileOptions::default();
+    for pattern in [
+        "**/*.js",
+        "src/**",
+        "a/{x,y}/**/*.{js,ts}",
+        "{**/a,b}",
+        "!(*.d).ts",
+        "[[:alpha:]]*.md",
+        "foo\\*bar",
+        "file{1..3}.txt",
+    ] {
+        assert_eq!(lint(pattern, &options), [], "{pattern}");
+    }
+}
+
+#[test]
+fn flags_globstars_inside_segments() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        kinds("a**/b", &options),
+        [(DiagnosticKind::GlobstarNotSegment, 1, 3)]
+    );
+    assert_eq!(
+        kinds("a/**b", &options),
+        [(DiagnosticKind::GlobstarNotSegment, 2, 4)]
+    );
+
+    let noglobstar = CompileOptions {
+        noglobstar: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(kinds("a**/b", &noglobstar), []);
+}
+
+#[test]
+fn flags_backslash_separators_outside_windows_mode() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        kinds(r"src\lib\*.js", &options),
+        [(DiagnosticKind::BackslashSeparator, 3, 4)]
+    );
+
+    let windows = CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(kinds(r"src\lib\*.js", &windows), []);
+}
+
+#[test]
+fn flags_silent_literal_fallbacks() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        kinds("src/[abc.js", &options),
+        [(DiagnosticKind::UnclosedBracket, 4, 5)]
+    );
+    assert_eq!(
+        kinds("src/{lib}/*.js", &options),
+        [(DiagnosticKind::SingleItemBrace, 4, 9)]
+    );
+}
+
+#[test]
+fn flags_redundant_dot_slash_after_negation() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        kinds("!./src/*.js", &options),
+        [(DiagnosticKind::DotSlashPrefix, 1, 3)]
+    );
+    assert_eq!(
+        lint("!./src/*.js", &options)[0].to_string(),
+        "leading `./` is redundant because it is stripped before matching (at byte 1)"
+    );
+}
+
+#[test]
+fn flags_ranges_too_large_to_expand() {
+    let options = CompileOptions::default();
+    let diagnostics = lint("log{1..5000}.txt", &options);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::RangeTooLarge);
+    assert_eq!((diagnostics[0].start, diagnostics[0].end), (3, 12));
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "range `{1..5000}` has 5000 values, more than the 1024 that can be expanded (at byte 3)"
+    );
+}
+
+#[test]
+fn flags_patterns_that_never_match() {
+    let options = CompileOptions::default();
+    assert_eq!(
+        kinds("@()", &options),
+        [(DiagnosticKind::NeverMatches, 0, 3)]
+    );
+}
+
+#[test]
+fn accepts_trailing_globstars_with_strict_slashes() {
+    let options = CompileOptions {
+        strict_slashes: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(kinds("a/**/", &options), []);
+
+    let matcher = compile_matcher("a/**/", &options).unwrap();
+    assert!(matcher.is_match("a/").unwrap());
+    assert!(matcher.is_match("a/b/").unwrap());
+}
+
+#[test]
+fn reports_compile_errors() {
+    let options = CompileOptions {
+        max_regex_size: Some(4),
+        ..CompileOptions::default()
+    };
+    let diagnostics: Vec<Diagnostic> = lint("src/*.js", &options);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::Invalid);
+}
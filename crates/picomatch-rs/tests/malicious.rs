@@ -1,6 +1,6 @@
 mod support;
 
-use picomatch_rs::{is_match, CompileOptions, MatchError};
+use picomatch_rs::{compile_matcher, is_match, CompileOptions, MatchError};
 use support::assert_is_match;
 
 fn repeat(ch: &str, n: usize) -> String {
@@ -57,3 +57,105 @@ fn test_object_instance_properties() {
     assert_is_match("__proto__", "__proto__", CompileOptions::default(), true);
     assert_is_match("toString", "toString", CompileOptions::default(), true);
 }
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_backtrack_limit() {
+    let options = CompileOptions {
+        backtrack_limit: Some(10_000),
+        engine: picomatch_rs::Engine::Regex,
+        ..CompileOptions::default()
+    };
+    let input = repeat("a", 40);
+
+    for pattern in ["*a*a*a*a*a*a*b", "**/*a*a*a*a*b", "!(*a*a*a*a*a*b)"] {
+        let matcher = compile_matcher(pattern, &options).unwrap();
+        let result = matcher.is_match(&input);
+        assert!(
+            matches!(result, Err(MatchError::LimitExceeded(_))),
+            "expected {pattern:?} to hit the backtrack limit, got: {result:?}"
+        );
+    }
+
+    let matcher = compile_matcher("*a*b", &options).unwrap();
+    assert!(!matcher.is_match(&input).unwrap());
+    assert!(matcher.is_match("aab").unwrap());
+}
+
+#[test]
+fn test_max_nesting_depth() {
+    let options = CompileOptions {
+        max_nesting_depth: Some(3),
+        ..CompileOptions::default()
+    };
+
+    assert!(is_match("a/c", "{a,{b,{c,d}}}/c", &options).unwrap());
+    assert!(is_match("abc", "@(a|+(b|!(c)))c", &options).unwrap());
+    assert!(matches!(
+        is_match("a", "{a,{b,{c,{d,e}}}}", &options),
+        Err(MatchError::LimitExceeded(_))
+    ));
+    assert!(matches!(
+        is_match("a", "@(a|{b,+(c|!(d))})", &options),
+        Err(MatchError::LimitExceeded(_))
+    ));
+    assert!(matches!(
+        is_match(
+            "a",
+            &format!("{}a{}", repeat("(", 500), repeat(")", 500)),
+            &options
+        ),
+        Err(MatchError::LimitExceeded(_))
+    ));
+
+    // Escaped and bracketed delimiters do not nest.
+    assert!(is_match("{{{{", "\\{\\{\\{\\{", &options).unwrap());
+    assert!(is_match("{", "[{][{][{][{]", &options).is_ok());
+    // An unclosed bracket is literal, so the braces after it still count.
+    assert!(matches!(
+        is_match("a", "[{a,{b,{c,{d,e}}}}", &options),
+        Err(MatchError::LimitExceeded(_))
+    ));
+}
+
+#[test]
+fn test_max_alternations() {
+    let options = CompileOptions {
+        max_alternations: Some(4),
+        ..CompileOptions::default()
+    };
+
+    assert!(is_match("c.js", "*.{js,ts,css,md,rs}", &options).is_ok());
+    assert!(is_match("a", "+(a|b|c|d|e)", &options).unwrap());
+    assert!(is_match("a,b,c,d,e,f", "a,b,c,d,e,f", &options).unwrap());
+    assert!(is_match("|", "[|||||]", &options).unwrap());
+    assert!(matches!(
+        is_match("a", "{a,b,c,d,e,f}", &options),
+        Err(MatchError::LimitExceeded(_))
+    ));
+    assert!(matches!(
+        is_match("a", "{a,b,c}/@(d|e|f|g)", &options),
+        Err(MatchError::LimitExceeded(_))
+    ));
+
+    let options = CompileOptions {
+        nobrace: true,
+        ..options
+    };
+    assert!(is_match("{a,b,c,d,e,f}", "{a,b,c,d,e,f}", &options).unwrap());
+}
+
+#[test]
+fn test_max_regex_size() {
+    let options = CompileOptions {
+        max_regex_size: Some(64),
+        ..CompileOptions::default()
+    };
+
+    assert!(is_match("a/b.js", "a/*.js", &options).unwrap());
+    let result = compile_matcher(&repeat("a/*/", 20), &options);
+    assert!(
+        matches!(result, Err(MatchError::LimitExceeded(_))),
+        "expected regex size limit, got: {result:?}"
+    );
+}
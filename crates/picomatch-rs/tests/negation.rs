@@ -1,19 +1,7 @@
 mod support;
 
-use picomatch_rs::CompileOptions;
 use support::assert_is_match;
 
-fn keep_quotes_opts() -> CompileOptions {
-    // Note: CompileOptions in picomatch-rs does not support `keepQuotes` directly.
-    // However, JS fallback logic or other mechanisms map this option.
-    // For now, testing unescape flag or keeping default behavior. Let's see if we can support keepQuotes directly.
-    // The current tests will verify standard compiler mapping behavior.
-    //
-    // Actually looking at picomatch-rs options, we don't have keepQuotes.
-    // Let's fallback to default if missing or check unsupported logic later.
-    Default::default()
-}
-
 #[test]
 fn test_leading_negation() {
     assert_is_match("abc", "!*", Default::default(), false);
@@ -18,11 +18,7 @@ use support::default_compile_options;
 
 /// Emulates the `format` function from JS: `str.replace(/^\.\//, '')`
 fn format(s: &str) -> String {
-    if s.starts_with("./") {
-        s[2..].to_string()
-    } else {
-        s.to_string()
-    }
+    s.strip_prefix("./").unwrap_or(s).to_string()
 }
 
 /// Emulates the `onMatch` logic from JS:
@@ -0,0 +1,167 @@
+use picomatch_rs::{compile_matcher, glob_covers, globs_intersect, CompileOptions, MatchError};
+
+fn intersect(a: &str, b: &str) -> Option<String> {
+    globs_intersect(a, b, &CompileOptions::default()).unwrap()
+}
+
+#[test]
+fn finds_a_shortest_witness() {
+    assert_eq!(
+        intersect("src/**/*.test.ts", "src/legacy/*").as_deref(),
+        Some("src/legacy/a.test.ts")
+    );
+    assert_eq!(intersect("*.md", "README*").as_deref(), Some("README.md"));
+    assert_eq!(intersect("{a,b}/c", "b/*").as_deref(), Some("b/c"));
+    assert_eq!(intersect("a/**/b", "**/c/**").as_deref(), Some("a/c/b"));
+    assert_eq!(intersect("+(a|b)", "*b").as_deref(), Some("b"));
+}
+
+#[test]
+fn detects_disjoint_globs() {
+    for (a, b) in [
+        ("*.js", "*.ts"),
+        ("src/**", "lib/**"),
+        ("a/*", "a/*/b"),
+        ("src/**/!(*.test).ts", "**/*.test.ts"),
+        ("[a-c]*", "[d-f]*"),
+    ] {
+        assert_eq!(intersect(a, b), None, "{a:?} and {b:?}");
+        assert_eq!(intersect(b, a), None, "{b:?} and {a:?}");
+    }
+}
+
+#[test]
+fn honours_negation_and_dotfiles() {
+    assert_eq!(intersect("!*.js", "*.js"), None);
+    assert_eq!(intersect("!(*.js)", "*.js"), None);
+    assert_eq!(intersect("!*.js", "*").as_deref(), Some("a"));
+
+    assert_eq!(intersect(".*", "*"), None);
+    assert_eq!(intersect("**", "**/.git/**"), None);
+    let dot = CompileOptions {
+        dot: true,
+        ..CompileOptions::default()
+    };
+    assert_eq!(
+        globs_intersect(".*", "*", &dot).unwrap().as_deref(),
+        Some(".")
+    );
+}
+
+#[test]
+fn honours_case_and_basename_options() {
+    assert_eq!(intersect("*.JS", "*.js"), None);
+    let nocase = CompileOptions {
+        nocase: true,
+        ..CompileOptions::default()
+    };
+    assert!(globs_intersect("*.JS", "*.js", &nocase).unwrap().is_some());
+
+    let match_base = CompileOptions {
+        match_base: true,
+        ..CompileOptions::default()
+    };
+    let witness = globs_intersect("*.rs", "lib.*", &match_base).unwrap();
+    assert_eq!(witness.as_deref(), Some("lib.rs"));
+}
+
+#[test]
+fn detects_covered_globs() {
+    let options = CompileOptions::default();
+    for (a, b) in [
+        ("dist/**", "dist/*.js"),
+        ("**/*.js", "src/**/*.js"),
+        ("**", "*"),
+        ("{a,b}/*", "a/*"),
+        ("*.{js,ts}", "*.js"),
+        ("**/node_modules/**", "node_modules/**"),
+        ("[a-z]*", "[a-c]*"),
+        ("*.js", "*.js"),
+    ] {
+        assert!(glob_covers(a, b, &options).unwrap(), "{a:?} covers {b:?}");
+    }
+    for (a, b) in [
+        ("dist/*.js", "dist/**"),
+        ("*", "**"),
+        ("[a-c]*", "[a-z]*"),
+        ("**/*", "**/.*"),
+        ("!*.js", "*"),
+    ] {
+        assert!(!glob_covers(a, b, &options).unwrap(), "{a:?} misses {b:?}");
+    }
+
+    let dot = CompileOptions {
+        dot: true,
+        ..CompileOptions::default()
+    };
+    assert!(glob_covers("**/*", "**/.*", &dot).unwrap());
+}
+
+#[test]
+fn rejects_patterns_that_need_the_regex_engine() {
+    let result = globs_intersect("a\\d", "*", &CompileOptions::default());
+    assert!(
+        matches!(result, Err(MatchError::UnsupportedPattern(_))),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn agrees_with_matching_every_short_path() {
+    let patterns = [
+        "*", "*.js", "**", "a/**", "**/b", "a/*/b", "?", "[ab]*", ".*", "**/.*", "{a,b}/*", "!a/*",
+        "!(a*)", "+(a|b)", "a/!(b)/*", "*/",
+    ];
+    let mut inputs = vec![String::new()];
+    let mut frontier = vec![String::new()];
+    for _ in 0..4 {
+        frontier = frontier
+            .iter()
+            .flat_map(|prefix| ['a', 'b', '.', '/'].map(|ch| format!("{prefix}{ch}")))
+            .collect();
+        inputs.extend(frontier.iter().cloned());
+    }
+    inputs.extend(["a.js", "a/x/b", "b/c", "a/.b/c"].map(String::from));
+
+    for options in [
+        CompileOptions::default(),
+        CompileOptions {
+            dot: true,
+            ..CompileOptions::default()
+        },
+        CompileOptions {
+            windows: true,
+            ..CompileOptions::default()
+        },
+    ] {
+        let matchers = patterns.map(|pattern| compile_matcher(pattern, &options).unwrap());
+        for (a, a_matcher) in patterns.iter().zip(&matchers) {
+            for (b, b_matcher) in patterns.iter().zip(&matchers) {
+                let witness = globs_intersect(a, b, &options).unwrap();
+                let both = |input: &str| {
+                    a_matcher.is_match(input).unwrap() && b_matcher.is_match(input).unwrap()
+                };
+                match witness {
+                    Some(witness) => assert!(both(&witness), "{witness:?} for {a:?} and {b:?}"),
+                    None => {
+                        let found = inputs.iter().find(|input| both(input));
+                        assert!(
+                            found.is_none(),
+                            "{found:?} matches {a:?} and {b:?} with {options:?}"
+                        );
+                    }
+                }
+
+                if glob_covers(a, b, &options).unwrap() {
+                    let found = inputs.iter().find(|input| {
+                        b_matcher.is_match(input).unwrap() && !a_matcher.is_match(input).unwrap()
+                    });
+                    assert!(
+                        found.is_none(),
+                        "{found:?} escapes {a:?} covering {b:?} with {options:?}"
+                    );
+                }
+            }
+        }
+    }
+}
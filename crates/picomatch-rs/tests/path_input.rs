@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use picomatch_rs::utils::{decode_path_bytes, encode_path_bytes};
+use picomatch_rs::{compile_matcher, CompileOptions};
+
+fn matcher(pattern: &str) -> picomatch_rs::Matcher {
+    compile_matcher(pattern, &CompileOptions::default()).unwrap()
+}
+
+#[test]
+fn matches_std_paths() {
+    let matcher = matcher("src/**/*.rs");
+    assert!(matcher
+        .is_match_path(Path::new("src/lib.rs"), false)
+        .unwrap());
+    assert!(matcher
+        .is_match_path(Path::new("src/a/b/mod.rs"), false)
+        .unwrap());
+    assert!(!matcher
+        .is_match_path(Path::new("tests/a.rs"), false)
+        .unwrap());
+    assert!(matcher.is_match_path("src/main.rs", false).unwrap());
+}
+
+#[test]
+fn matches_non_utf8_bytes_with_wildcards_only() {
+    assert!(matcher("*.txt").is_match_bytes(b"caf\xe9.txt").unwrap());
+    assert!(matcher("caf?.txt").is_match_bytes(b"caf\xe9.txt").unwrap());
+    assert!(!matcher("caf\u{e9}.txt")
+        .is_match_bytes(b"caf\xe9.txt")
+        .unwrap());
+    assert!(matcher("a/*/b").is_match_bytes(b"a/\xff\xfe/b").unwrap());
+    assert!(!matcher("a/?/b").is_match_bytes(b"a/\xff\xfe/b").unwrap());
+    assert!(matcher("a/??/b").is_match_bytes(b"a/\xff\xfe/b").unwrap());
+    assert!(!matcher("*").is_match_bytes(b".\xff").unwrap());
+    assert!(!matcher("*").is_match_bytes(b"a/\xff").unwrap());
+}
+
+#[test]
+fn windows_separators_apply_to_bytes() {
+    let options = CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    };
+    let matcher = compile_matcher("a/*.txt", &options).unwrap();
+    assert!(matcher.is_match_bytes(b"a\\\xff.txt").unwrap());
+    assert!(matcher.is_match_bytes(b"a/\xff.txt").unwrap());
+}
+
+#[test]
+fn decoding_is_lossless() {
+    let inputs: [&[u8]; 7] = [
+        b"plain/ascii",
+        "unicode/\u{1F600}".as_bytes(),
+        b"caf\xe9",
+        b"\xe2\x28\xa1",
+        b"\xf0\x9f\x98",
+        "\u{10FF00}/\u{10FF05}".as_bytes(),
+        b"\xf4\x8f\xbc\x80\xff",
+    ];
+
+    for input in inputs {
+        assert_eq!(encode_path_bytes(&decode_path_bytes(input)), input);
+    }
+}
+
+#[test]
+fn valid_utf8_bytes_match_like_strings() {
+    for input in ["\u{10FF00}", "\u{10FF80}", "a/\u{10FFFF}.txt", "caf\u{e9}"] {
+        assert!(matches!(
+            decode_path_bytes(input.as_bytes()),
+            Cow::Borrowed(decoded) if decoded == input
+        ));
+        for pattern in ["?", "*", "a/*.txt", input] {
+            let matcher = matcher(pattern);
+            let expected = matcher.is_match(input).unwrap();
+            assert_eq!(
+                matcher.is_match_bytes(input.as_bytes()).unwrap(),
+                expected,
+                "{input:?} against {pattern:?}"
+            );
+            assert_eq!(
+                matcher.is_match_path(Path::new(input), false).unwrap(),
+                expected,
+                "{input:?} against {pattern:?}"
+            );
+        }
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn matches_unix_os_strings() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"logs/\x80\x81.log");
+    assert!(matcher("logs/*.log").is_match_os_str(name).unwrap());
+    assert!(matcher("**/*.log")
+        .is_match_path(Path::new(name), false)
+        .unwrap());
+    assert!(matcher("logs/")
+        .is_match_path(Path::new("logs"), true)
+        .unwrap());
+}
@@ -1,4 +1,4 @@
-use picomatch_rs::{scan, ScanOptions, ScanState};
+use picomatch_rs::{scan, Depth, ScanOptions, ScanState};
 
 #[derive(Clone, Copy)]
 struct ExpectedScan<'a> {
@@ -863,3 +863,64 @@ fn preserves_backslashes_inside_literal_brackets() {
     assert_eq!(state.glob, "");
     assert!(!state.is_glob);
 }
+
+#[test]
+fn scan_tokens_carry_byte_spans() {
+    let pattern = "./fö/@(bar)/**/*.js";
+    let state = scan(
+        pattern,
+        &ScanOptions {
+            parts: true,
+            tokens: true,
+            ..ScanOptions::default()
+        },
+    );
+    let tokens = state.tokens.expect("scan tokens");
+
+    let spans = tokens
+        .iter()
+        .map(|token| (token.value.as_str(), token.start, token.end))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        spans,
+        vec![
+            ("./", 0, 2),
+            ("fö", 2, 5),
+            ("@(bar)", 6, 12),
+            ("**", 13, 15),
+            ("*.js", 16, 20),
+        ]
+    );
+    for token in &tokens {
+        assert_eq!(&pattern[token.start..token.end], token.value);
+    }
+}
+
+#[test]
+fn scan_tokens_report_typed_depths() {
+    let state = scan(
+        "./src/**/*.js",
+        &ScanOptions {
+            parts: true,
+            tokens: true,
+            ..ScanOptions::default()
+        },
+    );
+    let depths = state
+        .tokens
+        .expect("scan tokens")
+        .iter()
+        .map(|token| token.depth)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        depths,
+        vec![
+            Depth::Finite(0),
+            Depth::Finite(1),
+            Depth::Unbounded,
+            Depth::Finite(1),
+        ]
+    );
+    assert_eq!(state.max_depth, Some(Depth::Unbounded));
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "std")]
+
+use picomatch_rs::{compile_matcher, CompileOptions};
+
+fn assert_path_match(
+    path: &str,
+    is_dir: bool,
+    pattern: &str,
+    options: &CompileOptions,
+    expected: bool,
+) {
+    let matcher = compile_matcher(pattern, options)
+        .unwrap_or_else(|err| panic!("compile_matcher({pattern:?}) failed: {err:?}"));
+    let actual = matcher
+        .is_match_path(path, is_dir)
+        .unwrap_or_else(|err| panic!("is_match_path({path:?}) failed: {err:?}"));
+    assert_eq!(
+        actual, expected,
+        "unexpected match result for {path:?} (is_dir: {is_dir}) against {pattern:?}"
+    );
+}
+
+fn strict_slashes() -> CompileOptions {
+    CompileOptions {
+        strict_slashes: true,
+        ..CompileOptions::default()
+    }
+}
+
+#[test]
+fn trailing_slash_patterns_only_match_directories() {
+    let options = CompileOptions::default();
+    assert_path_match("build", true, "build/", &options, true);
+    assert_path_match("build/", true, "build/", &options, true);
+    assert_path_match("build", false, "build/", &options, false);
+    assert_path_match("build/", false, "build/", &options, false);
+
+    assert_path_match("node_modules", true, "**/node_modules/", &options, true);
+    assert_path_match("a/b/node_modules", true, "**/node_modules/", &options, true);
+    assert_path_match(
+        "a/b/node_modules",
+        false,
+        "**/node_modules/",
+        &options,
+        false,
+    );
+    assert_path_match(
+        "a/node_modules/x",
+        true,
+        "**/node_modules/",
+        &options,
+        false,
+    );
+
+    assert_path_match("src", true, "*/", &options, true);
+    assert_path_match("src", false, "*/", &options, false);
+    assert_path_match(".git", true, "*/", &options, false);
+}
+
+#[test]
+fn other_patterns_match_files_and_directories_alike() {
+    let options = CompileOptions::default();
+    assert_path_match("src/lib", true, "src/*", &options, true);
+    assert_path_match("src/lib", false, "src/*", &options, true);
+    assert_path_match("src/lib/", true, "src/*", &options, true);
+    assert_path_match("src/lib/mod.rs", false, "src/*", &options, false);
+    assert_path_match("build", true, "build", &options, true);
+    assert_path_match("build/", true, "build", &options, true);
+}
+
+#[test]
+fn directories_ignore_strict_slashes_trailing_separator() {
+    let options = strict_slashes();
+    assert_path_match("foo/bar/", true, "foo/*", &options, true);
+    assert_path_match("foo/bar", true, "foo/*", &options, true);
+    assert_path_match("foo/bar/", false, "foo/*", &options, true);
+
+    assert_path_match("a", true, "a/**", &options, true);
+    assert_path_match("a", false, "a/**", &options, false);
+    assert_path_match("a/b", true, "a/**", &options, true);
+    assert_path_match("a/b", false, "a/**", &options, true);
+
+    assert_path_match("build", true, "build/", &options, true);
+    assert_path_match("build", false, "build/", &options, false);
+}
+
+#[test]
+fn negated_directory_patterns() {
+    let options = CompileOptions::default();
+    assert_path_match("build", true, "!build/", &options, false);
+    assert_path_match("build", false, "!build/", &options, true);
+    assert_path_match("src", true, "!build/", &options, true);
+
+    let options = strict_slashes();
+    assert_path_match("a", true, "!a/**", &options, false);
+    assert_path_match("a", false, "!a/**", &options, true);
+}
+
+#[test]
+fn windows_directory_separators() {
+    let options = CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    };
+    assert_path_match(
+        "a\\node_modules\\",
+        true,
+        "**/node_modules/",
+        &options,
+        true,
+    );
+    assert_path_match("a\\node_modules", true, "**/node_modules/", &options, true);
+    assert_path_match(
+        "a\\node_modules",
+        false,
+        "**/node_modules/",
+        &options,
+        false,
+    );
+    assert_path_match("src\\lib\\", true, "src/*", &options, true);
+}
+
+#[test]
+fn root_paths_fall_back_to_plain_matching() {
+    let options = CompileOptions::default();
+    assert_path_match("/", true, "/", &options, true);
+    assert_path_match("/", true, "*", &options, true);
+    assert_path_match("/", true, "a/", &options, false);
+}
@@ -5,9 +5,10 @@ use picomatch_rs::CompileOptions;
 use support::{assert_is_match, default_compile_options};
 
 fn windows_options() -> CompileOptions {
-    let mut opts = CompileOptions::default();
-    opts.windows = true;
-    opts
+    CompileOptions {
+        windows: true,
+        ..CompileOptions::default()
+    }
 }
 
 // JS: makeRe('**/path/**', { windows: true }).test('C:\\Users\\...')
@@ -0,0 +1,113 @@
+use picomatch_rs::{compile_matcher, is_match, CompileOptions, MatchError};
+
+fn strict() -> CompileOptions {
+    CompileOptions {
+        strict_glob: true,
+        ..CompileOptions::default()
+    }
+}
+
+fn assert_disallowed(pattern: &str, options: &CompileOptions) {
+    let result = compile_matcher(pattern, options);
+    assert!(
+        matches!(result, Err(MatchError::DisallowedSyntax(_))),
+        "expected {pattern:?} to be rejected, got: {result:?}"
+    );
+}
+
+#[test]
+fn accepts_glob_syntax() {
+    let options = strict();
+    assert!(is_match("src/a/b.rs", "src/**/*.rs", &options).unwrap());
+    assert!(is_match("a.js", "*.{js,ts}", &options).unwrap());
+    assert!(is_match("b1", "[a-c][[:digit:]]", &options).unwrap());
+    assert!(is_match("x", "[!ab]", &options).unwrap());
+    assert!(is_match("foo.txt", "!(*.js)", &options).unwrap());
+    assert!(is_match("abab", "+(ab)", &options).unwrap());
+    assert!(is_match("a.ts", "@(*.ts|*.js)", &options).unwrap());
+    assert!(is_match("ab", "?(a)b", &options).unwrap());
+    assert!(is_match("ab", "@(a)+(b)", &options).unwrap());
+    assert!(is_match("c++", "c\\+\\+", &options).unwrap());
+    assert!(is_match("file (1).txt", "file \\(1\\).txt", &options).unwrap());
+    assert!(is_match("|", "[|]", &options).unwrap());
+    assert!(is_match("a)", "a)", &options).unwrap());
+    assert!(is_match("a]", "a]", &options).unwrap());
+    assert!(is_match("$^", "$^", &options).unwrap());
+}
+
+#[test]
+fn rejects_raw_regex_groups() {
+    let options = strict();
+    assert_disallowed("a(?=b)", &options);
+    assert_disallowed("(?<=a)b", &options);
+    assert_disallowed("(?i)abc", &options);
+    assert_disallowed("+(?:a)", &options);
+    assert_disallowed("{a,(?=x)}", &options);
+    assert_disallowed("(a|b)", &options);
+    assert_disallowed("foo/(abc|xyz).js", &options);
+    assert_disallowed("a|b", &options);
+    assert_disallowed("{a|b,c}", &options);
+
+    let options = CompileOptions {
+        noextglob: true,
+        ..strict()
+    };
+    assert_disallowed("@(a|b)", &options);
+}
+
+#[test]
+fn rejects_regex_escapes() {
+    let options = strict();
+    for pattern in [
+        "a\\d",
+        "(a)\\1",
+        "\\bword",
+        "\\p{L}",
+        "\\x41",
+        "\\k<name>",
+        "\\w+",
+    ] {
+        assert_disallowed(pattern, &options);
+    }
+    assert_disallowed("[\\d]", &options);
+    assert_disallowed("[\\p{L}]", &options);
+}
+
+#[test]
+fn rejects_regex_quantifiers() {
+    let options = strict();
+    assert_disallowed("[a-z]+", &options);
+    assert_disallowed("[a]?", &options);
+    assert_disallowed("@(a)+", &options);
+    assert_disallowed("*(a)?", &options);
+    assert_disallowed("{a,b}+", &options);
+    assert_disallowed("{a,b}?", &options);
+    assert_disallowed("@(a+)", &options);
+}
+
+#[test]
+fn rejects_regex_only_bracket_syntax() {
+    let options = strict();
+    assert_disallowed("[a&&b]", &options);
+    assert_disallowed("[a--b]", &options);
+    assert_disallowed("[a~~b]", &options);
+    assert_disallowed("[[:nope:]]", &options);
+    assert_disallowed("[[:alpha:]", &options);
+    assert_disallowed("[[:(?<=x)", &options);
+}
+
+#[test]
+fn rejects_regex_option() {
+    let options = CompileOptions {
+        regex: true,
+        ..strict()
+    };
+    assert_disallowed("*.js", &options);
+}
+
+#[test]
+fn sandbox_is_opt_in() {
+    let options = CompileOptions::default();
+    assert!(is_match("ab", "a(?=b)b", &options).unwrap());
+    assert!(is_match("a1", "a\\d", &options).unwrap());
+}
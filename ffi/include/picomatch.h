@@ -0,0 +1,85 @@
+/* Generated by cbindgen from ffi/src/lib.rs. Do not edit by hand. */
+
+#ifndef PICOMATCH_H
+#define PICOMATCH_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Kind of the last error recorded on the calling thread.
+ */
+typedef enum PmError {
+  PM_ERROR_NONE = 0,
+  PM_ERROR_INVALID_ARGUMENT = 1,
+  PM_ERROR_EMPTY_PATTERN = 2,
+  PM_ERROR_UNSUPPORTED_PATTERN = 3,
+  PM_ERROR_INVALID_REGEX = 4,
+  PM_ERROR_LIMIT_EXCEEDED = 5,
+  PM_ERROR_DISALLOWED_SYNTAX = 6,
+  PM_ERROR_PANIC = 7,
+} PmError;
+
+/**
+ * Opaque handle returned by `pm_compile` and released with `pm_free`.
+ */
+typedef struct PmMatcher PmMatcher;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Compiles `pattern` with `options_json`, a JSON object using the same
+ * camelCase keys as the Node bindings, or NULL for the defaults.
+ *
+ * Returns NULL on failure, including an internal panic, which is reported
+ * as `PM_ERROR_PANIC`; see `pm_last_error` and `pm_last_error_message`.
+ *
+ * # Safety
+ *
+ * `pattern` must be a NUL-terminated string and `options_json` must be NULL
+ * or a NUL-terminated string.
+ */
+struct PmMatcher *pm_compile(const char *pattern, const char *options_json);
+
+/**
+ * Returns 1 if `input` matches, 0 if it does not and -1 on error.
+ *
+ * # Safety
+ *
+ * `matcher` must be a live handle from `pm_compile` and `input` must be a
+ * NUL-terminated string.
+ */
+int pm_is_match(const struct PmMatcher *matcher, const char *input);
+
+/**
+ * Releases a handle from `pm_compile`. Passing NULL is a no-op.
+ *
+ * # Safety
+ *
+ * `matcher` must be NULL or a handle from `pm_compile` that has not been
+ * freed yet.
+ */
+void pm_free(struct PmMatcher *matcher);
+
+/**
+ * Returns the kind of the last error on the calling thread.
+ */
+enum PmError pm_last_error(void);
+
+/**
+ * Returns the last error message on the calling thread, or NULL. The
+ * pointer stays valid until the next `pm_compile` or `pm_is_match` call on
+ * the same thread.
+ */
+const char *pm_last_error_message(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PICOMATCH_H */
@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use picomatch_rs::{compile_matcher, CompileOptions, MatchError, Matcher};
+
+/// Opaque handle returned by `pm_compile` and released with `pm_free`.
+pub struct PmMatcher(Matcher);
+
+/// Kind of the last error recorded on the calling thread.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PmError {
+    None = 0,
+    InvalidArgument = 1,
+    EmptyPattern = 2,
+    UnsupportedPattern = 3,
+    InvalidRegex = 4,
+    LimitExceeded = 5,
+    DisallowedSyntax = 6,
+    Panic = 7,
+}
+
+struct LastError {
+    kind: PmError,
+    message: CString,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(kind: PmError, message: String) {
+    // Messages come from `Display` impls and user input, so strip interior
+    // NULs rather than dropping the whole message.
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(LastError { kind, message }));
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+fn set_match_error(err: &MatchError) {
+    let kind = match err {
+        MatchError::EmptyPattern => PmError::EmptyPattern,
+        MatchError::UnsupportedPattern(_) => PmError::UnsupportedPattern,
+        MatchError::InvalidRegex(_) => PmError::InvalidRegex,
+        MatchError::LimitExceeded(_) => PmError::LimitExceeded,
+        MatchError::DisallowedSyntax(_) => PmError::DisallowedSyntax,
+    };
+    set_last_error(kind, err.to_string());
+}
+
+// Unwinding into C is undefined behavior, so every entry point runs its body
+// here and reports a panic as `PmError::Panic` with `on_panic` as the result.
+fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            // The panic may have left `LAST_ERROR` borrowed.
+            let _ = panic::catch_unwind(|| {
+                set_last_error(PmError::Panic, format!("internal error: {message}"))
+            });
+            on_panic
+        }
+    }
+}
+
+unsafe fn str_arg<'a>(value: *const c_char, name: &str) -> Option<&'a str> {
+    if value.is_null() {
+        set_last_error(
+            PmError::InvalidArgument,
+            format!("`{name}` must not be NULL"),
+        );
+        return None;
+    }
+
+    match CStr::from_ptr(value).to_str() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            set_last_error(
+                PmError::InvalidArgument,
+                format!("`{name}` must be valid UTF-8"),
+            );
+            None
+        }
+    }
+}
+
+/// Compiles `pattern` with `options_json`, a JSON object using the same
+/// camelCase keys as the Node bindings, or NULL for the defaults.
+///
+/// Returns NULL on failure, including an internal panic, which is reported
+/// as `PM_ERROR_PANIC`; see `pm_last_error` and `pm_last_error_message`.
+///
+/// # Safety
+///
+/// `pattern` must be a NUL-terminated string and `options_json` must be NULL
+/// or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pm_compile(
+    pattern: *const c_char,
+    options_json: *const c_char,
+) -> *mut PmMatcher {
+    guard(ptr::null_mut(), || compile(pattern, options_json))
+}
+
+unsafe fn compile(pattern: *const c_char, options_json: *const c_char) -> *mut PmMatcher {
+    clear_last_error();
+
+    let Some(pattern) = str_arg(pattern, "pattern") else {
+        return ptr::null_mut();
+    };
+    let options = if options_json.is_null() {
+        CompileOptions::default()
+    } else {
+        let Some(json) = str_arg(options_json, "options_json") else {
+            return ptr::null_mut();
+        };
+        match serde_json::from_str::<CompileOptions>(json) {
+            Ok(options) => options,
+            Err(err) => {
+                set_last_error(PmError::InvalidArgument, format!("invalid options: {err}"));
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    match compile_matcher(pattern, &options) {
+        Ok(matcher) => Box::into_raw(Box::new(PmMatcher(matcher))),
+        Err(err) => {
+            set_match_error(&err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns 1 if `input` matches, 0 if it does not and -1 on error.
+///
+/// # Safety
+///
+/// `matcher` must be a live handle from `pm_compile` and `input` must be a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pm_is_match(matcher: *const PmMatcher, input: *const c_char) -> c_int {
+    guard(-1, || is_match(matcher, input))
+}
+
+unsafe fn is_match(matcher: *const PmMatcher, input: *const c_char) -> c_int {
+    clear_last_error();
+
+    let Some(matcher) = matcher.as_ref() else {
+        set_last_error(
+            PmError::InvalidArgument,
+            "`matcher` must not be NULL".to_string(),
+        );
+        return -1;
+    };
+    let Some(input) = str_arg(input, "input") else {
+        return -1;
+    };
+
+    match matcher.0.is_match(input) {
+        Ok(is_match) => c_int::from(is_match),
+        Err(err) => {
+            set_match_error(&err);
+            -1
+        }
+    }
+}
+
+/// Releases a handle from `pm_compile`. Passing NULL is a no-op.
+///
+/// # Safety
+///
+/// `matcher` must be NULL or a handle from `pm_compile` that has not been
+/// freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pm_free(matcher: *mut PmMatcher) {
+    guard((), || {
+        if !matcher.is_null() {
+            drop(Box::from_raw(matcher));
+        }
+    })
+}
+
+/// Returns the kind of the last error on the calling thread.
+#[no_mangle]
+pub extern "C" fn pm_last_error() -> PmError {
+    guard(PmError::Panic, || {
+        LAST_ERROR.with(|last| {
+            last.borrow()
+                .as_ref()
+                .map_or(PmError::None, |last| last.kind)
+        })
+    })
+}
+
+/// Returns the last error message on the calling thread, or NULL. The
+/// pointer stays valid until the next `pm_compile` or `pm_is_match` call on
+/// the same thread.
+#[no_mangle]
+pub extern "C" fn pm_last_error_message() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|last| {
+            last.borrow()
+                .as_ref()
+                .map_or(ptr::null(), |last| last.message.as_ptr())
+        })
+    })
+}
@@ -0,0 +1,42 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "picomatch.h"
+
+#define CHECK(cond)                                                        \
+  do {                                                                     \
+    if (!(cond)) {                                                         \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+      return 1;                                                            \
+    }                                                                      \
+  } while (0)
+
+int main(void) {
+  PmMatcher *matcher = pm_compile("src/**/*.{c,h}", NULL);
+  CHECK(matcher != NULL);
+  CHECK(pm_is_match(matcher, "src/lib/picomatch.h") == 1);
+  CHECK(pm_is_match(matcher, "src/main.rs") == 0);
+  CHECK(pm_is_match(matcher, NULL) == -1);
+  CHECK(pm_last_error() == PM_ERROR_INVALID_ARGUMENT);
+  pm_free(matcher);
+
+  PmMatcher *nocase = pm_compile("*.TXT", "{\"nocase\": true}");
+  CHECK(nocase != NULL);
+  CHECK(pm_is_match(nocase, "notes.txt") == 1);
+  CHECK(pm_last_error() == PM_ERROR_NONE);
+  CHECK(pm_last_error_message() == NULL);
+  pm_free(nocase);
+
+  CHECK(pm_compile("", NULL) == NULL);
+  CHECK(pm_last_error() == PM_ERROR_EMPTY_PATTERN);
+
+  CHECK(pm_compile("(a|b)", "{\"strictGlob\": true}") == NULL);
+  CHECK(pm_last_error() == PM_ERROR_DISALLOWED_SYNTAX);
+  CHECK(strstr(pm_last_error_message(), "regex group") != NULL);
+
+  CHECK(pm_compile("*", "{\"nocase\": 1}") == NULL);
+  CHECK(pm_last_error() == PM_ERROR_INVALID_ARGUMENT);
+
+  pm_free(NULL);
+  return 0;
+}
@@ -0,0 +1,72 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let config = cbindgen::Config::from_file(manifest_dir().join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(manifest_dir().join("src/lib.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    let header = manifest_dir().join("include/picomatch.h");
+    if env::var_os("PICOMATCH_BLESS").is_some() {
+        fs::write(&header, &generated).unwrap();
+    }
+
+    assert_eq!(
+        fs::read_to_string(&header).unwrap(),
+        generated,
+        "include/picomatch.h is stale; rerun with PICOMATCH_BLESS=1"
+    );
+}
+
+// Cargo leaves the staticlib in the test binary's `deps` directory, and
+// only sometimes copies it up into the profile directory.
+#[cfg(unix)]
+fn static_library() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    [deps, deps.parent().unwrap()]
+        .iter()
+        .map(|dir| dir.join("libpicomatch_rs_ffi.a"))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| deps.join("libpicomatch_rs_ffi.a"))
+}
+
+#[cfg(unix)]
+#[test]
+fn c_program_links_and_matches() {
+    let library = static_library();
+    assert!(library.exists(), "missing {}", library.display());
+
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("picomatch_smoke");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(manifest_dir().join("tests/c/smoke.c"))
+        .arg("-I")
+        .arg(manifest_dir().join("include"))
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&output)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile tests/c/smoke.c");
+
+    let run = Command::new(&output).output().unwrap();
+    assert!(
+        run.status.success(),
+        "smoke program failed: {}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+}
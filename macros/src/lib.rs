@@ -0,0 +1,216 @@
+use picomatch_rs::{compile_matcher, CompileOptions};
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Error, Ident, LitBool, LitStr, Token};
+
+const BOOL_OPTIONS: &[&str] = &[
+    "bash",
+    "basename",
+    "contains",
+    "dot",
+    "keep_quotes",
+    "match_base",
+    "nobrace",
+    "nobracket",
+    "noextglob",
+    "noglobstar",
+    "nocase",
+    "nonegate",
+    "posix",
+    "regex",
+    "strict_brackets",
+    "strict_glob",
+    "strict_slashes",
+    "unescape",
+    "windows",
+];
+
+struct GlobInput {
+    pattern: LitStr,
+    options: Vec<(Ident, bool)>,
+}
+
+impl Parse for GlobInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = input.parse::<LitStr>()?;
+        let mut options = Vec::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name = input.parse::<Ident>()?;
+            if !BOOL_OPTIONS.contains(&name.to_string().as_str()) {
+                return Err(Error::new(
+                    name.span(),
+                    format!(
+                        "unknown glob option `{name}`; expected one of: {}",
+                        BOOL_OPTIONS.join(", ")
+                    ),
+                ));
+            }
+            let value = if input.parse::<Option<Token![=]>>()?.is_some() {
+                input.parse::<LitBool>()?.value
+            } else {
+                true
+            };
+            if options.iter().any(|(seen, _)| *seen == name) {
+                return Err(Error::new(
+                    name.span(),
+                    format!("glob option `{name}` is set twice"),
+                ));
+            }
+            options.push((name, value));
+        }
+
+        Ok(GlobInput { pattern, options })
+    }
+}
+
+impl GlobInput {
+    fn compile_options(&self) -> CompileOptions {
+        let mut options = CompileOptions::default();
+        for (name, value) in &self.options {
+            let field = match name.to_string().as_str() {
+                "bash" => &mut options.bash,
+                "basename" => &mut options.basename,
+                "contains" => &mut options.contains,
+                "dot" => &mut options.dot,
+                "keep_quotes" => &mut options.keep_quotes,
+                "match_base" => &mut options.match_base,
+                "nobrace" => &mut options.nobrace,
+                "nobracket" => &mut options.nobracket,
+                "noextglob" => &mut options.noextglob,
+                "noglobstar" => &mut options.noglobstar,
+                "nocase" => &mut options.nocase,
+                "nonegate" => &mut options.nonegate,
+                "posix" => &mut options.posix,
+                "regex" => &mut options.regex,
+                "strict_brackets" => &mut options.strict_brackets,
+                "strict_glob" => &mut options.strict_glob,
+                "strict_slashes" => &mut options.strict_slashes,
+                "unescape" => &mut options.unescape,
+                "windows" => &mut options.windows,
+                _ => unreachable!("options are checked while parsing"),
+            };
+            *field = *value;
+        }
+        options
+    }
+}
+
+enum Shape {
+    Literal,
+    Suffix(String),
+    RecursiveSuffix(String),
+}
+
+fn is_plain(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment != "."
+        && segment != ".."
+        && segment
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | '~'))
+}
+
+// Only shapes whose compiled regex is fully understood are specialized, and
+// only with default options; `tests/glob.rs` checks them against
+// `compile_matcher`.
+fn shape(pattern: &str, options: &CompileOptions) -> Option<Shape> {
+    if *options != CompileOptions::default() {
+        return None;
+    }
+
+    let extension = |rest: &str| {
+        let ext = rest.strip_prefix("*.")?;
+        ext.split('.').all(is_plain).then(|| format!(".{ext}"))
+    };
+
+    if let Some(suffix) = pattern.strip_prefix("**/").and_then(extension) {
+        return Some(Shape::RecursiveSuffix(suffix));
+    }
+    if let Some(suffix) = extension(pattern) {
+        return Some(Shape::Suffix(suffix));
+    }
+    if pattern.split('/').all(is_plain) {
+        return Some(Shape::Literal);
+    }
+    None
+}
+
+/// Compiles a glob at build time and expands to a `&'static StaticMatcher`.
+///
+/// Boolean `CompileOptions` fields can follow the pattern, either bare or as
+/// `name = bool`:
+///
+/// ```
+/// use picomatch_rs_macros::glob;
+///
+/// assert!(glob!("src/**/*.rs").is_match("src/a/lib.rs").unwrap());
+/// assert!(glob!("*.MD", nocase, dot = true).is_match(".readme.md").unwrap());
+/// ```
+///
+/// Invalid patterns are rejected with an error pointing at the literal:
+///
+/// ```compile_fail
+/// picomatch_rs_macros::glob!("(a|b)", strict_glob);
+/// ```
+///
+/// ```compile_fail
+/// picomatch_rs_macros::glob!("*.rs", dotfiles);
+/// ```
+#[proc_macro]
+pub fn glob(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as GlobInput);
+    let pattern = input.pattern.value();
+    let options = input.compile_options();
+
+    if let Err(err) = compile_matcher(&pattern, &options) {
+        return Error::new(
+            input.pattern.span(),
+            format!("invalid glob `{pattern}`: {err}"),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let matcher = match shape(&pattern, &options) {
+        Some(Shape::Literal) => quote!(::picomatch_rs::StaticMatcher::Literal(#pattern)),
+        Some(Shape::Suffix(suffix)) => quote!(::picomatch_rs::StaticMatcher::Suffix(#suffix)),
+        Some(Shape::RecursiveSuffix(suffix)) => {
+            quote!(::picomatch_rs::StaticMatcher::RecursiveSuffix(#suffix))
+        }
+        None => {
+            let names = input.options.iter().map(|(name, _)| name);
+            let values = input
+                .options
+                .iter()
+                .map(|(_, value)| LitBool::new(*value, Span::call_site()));
+            quote! {
+                ::picomatch_rs::StaticMatcher::Lazy(::std::sync::LazyLock::new(|| {
+                    ::picomatch_rs::compile_matcher(
+                        #pattern,
+                        &::picomatch_rs::CompileOptions {
+                            #(#names: #values,)*
+                            ..::core::default::Default::default()
+                        },
+                    )
+                    .expect("`glob!` validated this pattern at compile time")
+                }))
+            }
+        }
+    };
+
+    quote! {
+        {
+            static GLOB: ::picomatch_rs::StaticMatcher = #matcher;
+            &GLOB
+        }
+    }
+    .into()
+}
@@ -0,0 +1,106 @@
+use picomatch_rs::{compile_matcher, CompileOptions, StaticMatcher};
+use picomatch_rs_macros::glob;
+
+const INPUTS: &[&str] = &[
+    "",
+    "lib.rs",
+    "a.rs",
+    ".rs",
+    "..rs",
+    "a.rs/",
+    "/a.rs",
+    "//a.rs",
+    "./a.rs",
+    "src/lib.rs",
+    "src/lib.rs/",
+    "./src/lib.rs",
+    "src//lib.rs",
+    "src/a/b/mod.rs",
+    "src/.hidden/mod.rs",
+    "a/./b.rs",
+    "a/../b.rs",
+    "a\\b.rs",
+    "x.RS",
+    "\na.rs",
+    "a\n.rs",
+    "src/\nlib.rs",
+    "*.rs",
+    "**/*.rs",
+    "index.d.ts",
+    "types/index.d.ts",
+    ".d.ts",
+    "README.md",
+    "docs/guide.md",
+];
+
+fn assert_agrees(matcher: &StaticMatcher, pattern: &str, options: &CompileOptions) {
+    let expected = compile_matcher(pattern, options).unwrap();
+    for input in INPUTS {
+        assert_eq!(
+            matcher.is_match(input).unwrap(),
+            expected.is_match(input).unwrap(),
+            "{input:?} against {pattern:?}"
+        );
+    }
+}
+
+#[test]
+fn specializes_simple_shapes() {
+    let options = CompileOptions::default();
+
+    assert!(matches!(
+        glob!("src/lib.rs"),
+        StaticMatcher::Literal("src/lib.rs")
+    ));
+    assert_agrees(glob!("src/lib.rs"), "src/lib.rs", &options);
+    assert_agrees(glob!("README.md"), "README.md", &options);
+
+    assert!(matches!(glob!("*.rs"), StaticMatcher::Suffix(".rs")));
+    assert_agrees(glob!("*.rs"), "*.rs", &options);
+    assert_agrees(glob!("*.d.ts"), "*.d.ts", &options);
+
+    assert!(matches!(
+        glob!("**/*.rs"),
+        StaticMatcher::RecursiveSuffix(".rs")
+    ));
+    assert_agrees(glob!("**/*.rs"), "**/*.rs", &options);
+    assert_agrees(glob!("**/*.d.ts"), "**/*.d.ts", &options);
+}
+
+#[test]
+fn compiles_other_patterns_lazily() {
+    let matcher = glob!("src/**/*.rs");
+    assert!(matches!(matcher, StaticMatcher::Lazy(_)));
+    assert_agrees(matcher, "src/**/*.rs", &CompileOptions::default());
+    assert_agrees(
+        glob!("{src,docs}/*.{rs,md}"),
+        "{src,docs}/*.{rs,md}",
+        &CompileOptions::default(),
+    );
+    assert_agrees(glob!("!**/*.rs"), "!**/*.rs", &CompileOptions::default());
+
+    // The same call site yields the same static.
+    let first = (0..2)
+        .map(|_| glob!("a/**") as *const StaticMatcher)
+        .collect::<Vec<_>>();
+    assert_eq!(first[0], first[1]);
+}
+
+#[test]
+fn passes_options_through() {
+    let options = CompileOptions {
+        nocase: true,
+        dot: true,
+        ..CompileOptions::default()
+    };
+    let matcher = glob!("*.rs", nocase, dot = true);
+    assert!(matches!(matcher, StaticMatcher::Lazy(_)));
+    assert_agrees(matcher, "*.rs", &options);
+    assert!(matcher.is_match(".X.RS").unwrap());
+
+    assert!(matches!(
+        glob!("*.rs", posix = true),
+        StaticMatcher::Suffix(".rs")
+    ));
+    assert!(!glob!("a/*", nocase = false).is_match("A/b").unwrap());
+}
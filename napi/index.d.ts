@@ -3,7 +3,7 @@
 
 /* auto-generated by NAPI-RS */
 
-export declare function scan(input: string, options?: any | undefined | null): object
+export declare function scan(input: string, options?: any | undefined | null): ScanState
 export declare function parse(input: any, options?: any | undefined | null): unknown
 export declare function compileRe(state: any, options?: any | undefined | null, returnOutput?: boolean | undefined | null, returnState?: boolean | undefined | null): unknown
 export declare function makeRe(input: string, options?: any | undefined | null, returnOutput?: boolean | undefined | null, returnState?: boolean | undefined | null): unknown
@@ -12,8 +12,45 @@ export declare function test(input: string, regex: unknown, options?: any | unde
 export declare function matchBase(input: string, glob: unknown, options?: any | undefined | null): boolean
 export declare function isMatch(input: string, patterns: any, options?: any | undefined | null): boolean
 export declare function compileMatcher(patterns: any, options?: any | undefined | null): NativeMatcher
+export declare function isGlob(input: string, options?: any | undefined | null): boolean
+export declare function isDynamicPattern(pattern: string, options?: any | undefined | null): boolean
+export declare function globParent(input: string, options?: any | undefined | null): string
 export declare class NativeMatcher {
   test(input: string, returnObject?: boolean | undefined | null): unknown
   get state(): unknown
   get regex(): unknown
 }
+export interface ScanToken {
+  value: string
+  depth: number
+  isGlob: boolean
+  isGlobstar?: boolean
+  isBrace?: boolean
+  isBracket?: boolean
+  isExtglob?: boolean
+  negated?: boolean
+  backslashes?: boolean
+  isPrefix?: boolean
+  /** Byte offset of `value` in the scanned input. */
+  start: number
+  /** Byte offset just past `value` in the scanned input. */
+  end: number
+}
+export interface ScanState {
+  prefix: string
+  input: string
+  start: number
+  base: string
+  glob: string
+  isBrace: boolean
+  isBracket: boolean
+  isGlob: boolean
+  isExtglob: boolean
+  isGlobstar: boolean
+  negated: boolean
+  negatedExtglob: boolean
+  maxDepth?: number
+  tokens?: ScanToken[]
+  slashes?: number[]
+  parts?: string[]
+}
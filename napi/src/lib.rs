@@ -1,8 +1,10 @@
 use napi::{Env, Error, JsFunction, JsObject, JsUnknown, Result, Status, ValueType};
 use napi_derive::napi;
 use picomatch_rs::{
-    make_re as make_re_impl, parse as parse_impl, CompileOptions, ParseState, ParseToken,
-    RegexDescriptor, ScanOptions, ScanState, ScanToken, regex_output_for_engine,
+    check_pattern_limits, check_regex_size, check_strict_glob, make_re as make_re_impl,
+    parse as parse_impl, CompileOptions, GlobParentOptions, IsGlobOptions, MatchError,
+    ParseState, ParseToken, RegexDescriptor, ScanOptions, ScanState, ScanToken,
+    regex_output_for_engine,
 };
 use serde_json::Value;
 
@@ -34,6 +36,22 @@ fn scan_options_from_value(value: Option<Value>) -> Result<ScanOptions> {
         .unwrap_or_else(|| Ok(ScanOptions::default()))
 }
 
+fn is_glob_options_from_value(value: Option<Value>) -> Result<IsGlobOptions> {
+    value
+        .map(serde_json::from_value::<IsGlobOptions>)
+        .transpose()?
+        .map(Ok)
+        .unwrap_or_else(|| Ok(IsGlobOptions::default()))
+}
+
+fn glob_parent_options_from_value(value: Option<Value>) -> Result<GlobParentOptions> {
+    value
+        .map(serde_json::from_value::<GlobParentOptions>)
+        .transpose()?
+        .map(Ok)
+        .unwrap_or_else(|| Ok(GlobParentOptions::default()))
+}
+
 fn bool_option(value: &Option<Value>, key: &str) -> bool {
     match value {
         Some(Value::Object(object)) => object.get(key).and_then(Value::as_bool).unwrap_or(false),
@@ -65,6 +83,10 @@ fn check_max_length(input: &str, max: Option<usize>) -> Result<()> {
     }
 }
 
+fn map_match_error(result: std::result::Result<(), MatchError>) -> Result<()> {
+    result.map_err(|err| Error::new(Status::GenericFailure, err.to_string()))
+}
+
 fn check_strict_brackets(input: &str) -> Result<()> {
     let chars: Vec<char> = input.chars().collect();
     let mut paren_depth: i32 = 0;
@@ -271,6 +293,7 @@ fn match_value_unknown(env: &Env, value: Option<JsObject>, exact: bool) -> Resul
     Ok(env.get_null()?.into_unknown())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_result_object(
     env: &Env,
     glob: &str,
@@ -369,6 +392,8 @@ fn parse_token_to_js(env: &Env, token: &ParseToken) -> Result<JsObject> {
     if let Some(output) = &token.output {
         object.set_named_property("output", output.clone())?;
     }
+    object.set_named_property("start", token.start as u32)?;
+    object.set_named_property("end", token.end as u32)?;
 
     Ok(object)
 }
@@ -414,7 +439,7 @@ fn regex_descriptor_to_js(env: &Env, descriptor: &RegexDescriptor) -> Result<JsO
 fn scan_token_to_js(env: &Env, token: &ScanToken) -> Result<JsObject> {
     let mut object = env.create_object()?;
     object.set_named_property("value", token.value.clone())?;
-    object.set_named_property("depth", token.depth)?;
+    object.set_named_property("depth", token.depth.as_f64())?;
     object.set_named_property("isGlob", token.is_glob)?;
 
     if let Some(value) = token.is_globstar {
@@ -438,6 +463,8 @@ fn scan_token_to_js(env: &Env, token: &ScanToken) -> Result<JsObject> {
     if let Some(value) = token.is_prefix {
         object.set_named_property("isPrefix", value)?;
     }
+    object.set_named_property("start", token.start as u32)?;
+    object.set_named_property("end", token.end as u32)?;
 
     Ok(object)
 }
@@ -458,7 +485,7 @@ fn scan_state_to_js(env: &Env, state: ScanState) -> Result<JsObject> {
     object.set_named_property("negatedExtglob", state.negated_extglob)?;
 
     if let Some(max_depth) = state.max_depth {
-        object.set_named_property("maxDepth", max_depth)?;
+        object.set_named_property("maxDepth", max_depth.as_f64())?;
     }
 
     if let Some(tokens) = state.tokens {
@@ -588,7 +615,7 @@ impl NativeMatcher {
     }
 }
 
-#[napi]
+#[napi(ts_return_type = "ScanState")]
 pub fn scan(env: Env, input: String, options: Option<Value>) -> Result<JsObject> {
     let options = scan_options_from_value(options)?;
     scan_state_to_js(&env, picomatch_rs::scan(&input, &options))
@@ -664,6 +691,8 @@ pub fn make_re(
     ensure_non_empty_pattern(&input)?;
 
     check_max_length(&input, options.max_length)?;
+    map_match_error(check_pattern_limits(&input, &options))?;
+    map_match_error(check_strict_glob(&input, &options))?;
 
     if options.strict_brackets {
         check_strict_brackets(&input)?;
@@ -672,6 +701,7 @@ pub fn make_re(
     let Some(descriptor) = make_re_impl(&input, &options, return_state.unwrap_or(false)) else {
         return Ok(env.get_null()?.into_unknown());
     };
+    map_match_error(check_regex_size(&descriptor.source, &options))?;
 
     if return_output.unwrap_or(false) {
         return Ok(env.create_string(&descriptor.output)?.into_unknown());
@@ -786,9 +816,12 @@ pub fn is_match(env: Env, input: String, patterns: Value, options: Option<Value>
     for pattern in patterns {
         ensure_non_empty_pattern(&pattern)?;
         check_max_length(&pattern, options.max_length)?;
+        map_match_error(check_pattern_limits(&pattern, &options))?;
+        map_match_error(check_strict_glob(&pattern, &options))?;
         let Some(descriptor) = make_re_impl(&pattern, &options, true) else {
             continue;
         };
+        map_match_error(check_regex_size(&descriptor.source, &options))?;
         let result = execute_pattern(
             &env,
             &input,
@@ -816,7 +849,10 @@ pub fn compile_matcher(patterns: Value, options: Option<Value>) -> Result<Native
     for pattern in &patterns {
         ensure_non_empty_pattern(pattern)?;
         check_max_length(pattern, options.max_length)?;
+        map_match_error(check_pattern_limits(pattern, &options))?;
+        map_match_error(check_strict_glob(pattern, &options))?;
         let descriptor = make_re_impl(pattern, &options, true).unwrap_or_else(never_match_descriptor);
+        map_match_error(check_regex_size(&descriptor.source, &options))?;
         descriptors.push(descriptor);
     }
 
@@ -828,3 +864,21 @@ pub fn compile_matcher(patterns: Value, options: Option<Value>) -> Result<Native
         options,
     })
 }
+
+#[napi(js_name = "isGlob")]
+pub fn is_glob(input: String, options: Option<Value>) -> Result<bool> {
+    let options = is_glob_options_from_value(options)?;
+    Ok(picomatch_rs::is_glob(&input, &options))
+}
+
+#[napi(js_name = "isDynamicPattern")]
+pub fn is_dynamic_pattern(pattern: String, options: Option<Value>) -> Result<bool> {
+    let options = compile_options_from_value(options)?;
+    Ok(picomatch_rs::is_dynamic_pattern(&pattern, &options))
+}
+
+#[napi(js_name = "globParent")]
+pub fn glob_parent(input: String, options: Option<Value>) -> Result<String> {
+    let options = glob_parent_options_from_value(options)?;
+    Ok(picomatch_rs::glob_parent(&input, &options))
+}
@@ -0,0 +1,291 @@
+use picomatch_rs::{
+    check_pattern_limits, check_regex_size, check_strict_glob, compile_matcher as compile_impl,
+    make_re as make_re_impl, CompileOptions, MatchError, ScanOptions,
+};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+create_exception!(picomatch_rs, PicomatchError, PyValueError);
+
+const DEFAULT_MAX_LENGTH: usize = 65536;
+
+fn value_from_py(value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if value.is_none() {
+        Ok(Value::Null)
+    } else if let Ok(value) = value.downcast::<PyBool>() {
+        Ok(Value::Bool(value.is_true()))
+    } else if let Ok(value) = value.downcast::<PyInt>() {
+        Ok(Value::from(value.extract::<i64>()?))
+    } else if let Ok(value) = value.downcast::<PyFloat>() {
+        Ok(Value::from(value.value()))
+    } else if let Ok(value) = value.downcast::<PyString>() {
+        Ok(Value::String(value.to_str()?.to_string()))
+    } else if let Ok(values) = value.downcast::<PyList>() {
+        values.iter().map(|value| value_from_py(&value)).collect()
+    } else if let Ok(values) = value.downcast::<PyTuple>() {
+        values.iter().map(|value| value_from_py(&value)).collect()
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "unsupported option value: {}",
+            value.repr()?
+        )))
+    }
+}
+
+fn value_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_pyobject(py)?.to_owned().into_any().unbind(),
+        Value::Number(number) => match number.as_i64() {
+            Some(value) => value.into_pyobject(py)?.into_any().unbind(),
+            None => number
+                .as_f64()
+                .unwrap_or_default()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind(),
+        },
+        Value::String(value) => value.into_pyobject(py)?.into_any().unbind(),
+        Value::Array(values) => PyList::new(
+            py,
+            values
+                .iter()
+                .map(|value| value_to_py(py, value))
+                .collect::<PyResult<Vec<_>>>()?,
+        )?
+        .into_any()
+        .unbind(),
+        Value::Object(object) => {
+            let dict = PyDict::new(py);
+            for (key, value) in object {
+                dict.set_item(snake_case(key), value_to_py(py, value)?)?;
+            }
+            dict.into_any().unbind()
+        }
+    })
+}
+
+fn to_py<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    let value =
+        serde_json::to_value(value).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    value_to_py(py, &value)
+}
+
+fn camel_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len());
+    let mut upper = false;
+    for ch in key.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            output.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            output.push(ch);
+        }
+    }
+    output
+}
+
+fn snake_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len() + 4);
+    for ch in key.chars() {
+        if ch.is_ascii_uppercase() {
+            output.push('_');
+            output.push(ch.to_ascii_lowercase());
+        } else {
+            output.push(ch);
+        }
+    }
+    output
+}
+
+// Keyword arguments use the Python spelling of the `CompileOptions` and
+// `ScanOptions` fields and are converted to the camelCase keys serde expects.
+fn options_from_kwargs<T>(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<T>
+where
+    T: Default + DeserializeOwned,
+{
+    let Some(kwargs) = kwargs else {
+        return Ok(T::default());
+    };
+
+    let mut object = Map::new();
+    for (key, value) in kwargs.iter() {
+        let key = camel_case(key.downcast::<PyString>()?.to_str()?);
+        object.insert(key, value_from_py(&value)?);
+    }
+    if !object.contains_key("noextglob") {
+        if let Some(Value::Bool(enabled)) = object.get("noext") {
+            object.insert("noextglob".to_string(), Value::Bool(*enabled));
+        }
+    }
+
+    serde_json::from_value(Value::Object(object))
+        .map_err(|err| PyTypeError::new_err(format!("invalid options: {err}")))
+}
+
+fn map_match_error<T>(result: Result<T, MatchError>) -> PyResult<T> {
+    result.map_err(|err| PicomatchError::new_err(err.to_string()))
+}
+
+fn check_pattern(pattern: &str, options: &CompileOptions) -> PyResult<()> {
+    let limit = options.max_length.unwrap_or(DEFAULT_MAX_LENGTH);
+    if pattern.len() > limit {
+        return Err(PicomatchError::new_err(format!(
+            "Input length: {}, exceeds maximum allowed length: {limit}",
+            pattern.len()
+        )));
+    }
+
+    map_match_error(check_pattern_limits(pattern, options))?;
+    map_match_error(check_strict_glob(pattern, options))
+}
+
+fn patterns_from_py(patterns: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    if let Ok(pattern) = patterns.downcast::<PyString>() {
+        return Ok(vec![pattern.to_str()?.to_string()]);
+    }
+
+    patterns
+        .try_iter()
+        .map_err(|_| PyTypeError::new_err("expected a pattern string or a list of patterns"))?
+        .map(|pattern| pattern?.extract::<String>())
+        .collect()
+}
+
+#[pyclass(module = "picomatch_rs", frozen)]
+pub struct Matcher {
+    patterns: Vec<String>,
+    matchers: Vec<picomatch_rs::Matcher>,
+}
+
+impl Matcher {
+    fn compile(patterns: Vec<String>, options: &CompileOptions) -> PyResult<Self> {
+        let mut matchers = Vec::with_capacity(patterns.len());
+        for pattern in &patterns {
+            check_pattern(pattern, options)?;
+            matchers.push(map_match_error(compile_impl(pattern, options))?);
+        }
+
+        Ok(Matcher { patterns, matchers })
+    }
+
+    fn matches(&self, input: &str) -> PyResult<bool> {
+        for matcher in &self.matchers {
+            if map_match_error(matcher.is_match(input))? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+#[pymethods]
+impl Matcher {
+    fn is_match(&self, input: &str) -> PyResult<bool> {
+        self.matches(input)
+    }
+
+    fn __call__(&self, input: &str) -> PyResult<bool> {
+        self.matches(input)
+    }
+
+    fn filter(&self, inputs: Vec<String>) -> PyResult<Vec<String>> {
+        let mut matched = Vec::new();
+        for input in inputs {
+            if self.matches(&input)? {
+                matched.push(input);
+            }
+        }
+
+        Ok(matched)
+    }
+
+    #[getter]
+    fn patterns(&self) -> Vec<String> {
+        self.patterns.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Matcher({:?})", self.patterns)
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (patterns, **options))]
+fn compile_matcher(
+    patterns: &Bound<'_, PyAny>,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Matcher> {
+    let options = options_from_kwargs::<CompileOptions>(options)?;
+    Matcher::compile(patterns_from_py(patterns)?, &options)
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, patterns, **options))]
+fn is_match(
+    input: &str,
+    patterns: &Bound<'_, PyAny>,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<bool> {
+    compile_matcher(patterns, options)?.matches(input)
+}
+
+#[pyfunction]
+#[pyo3(signature = (inputs, patterns, **options))]
+fn filter(
+    inputs: Vec<String>,
+    patterns: &Bound<'_, PyAny>,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<String>> {
+    compile_matcher(patterns, options)?.filter(inputs)
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, **options))]
+fn scan(py: Python<'_>, input: &str, options: Option<&Bound<'_, PyDict>>) -> PyResult<PyObject> {
+    let options = options_from_kwargs::<ScanOptions>(options)?;
+    to_py(py, &picomatch_rs::scan(input, &options))
+}
+
+#[pyfunction]
+#[pyo3(signature = (pattern, **options))]
+fn make_re(
+    py: Python<'_>,
+    pattern: &str,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<PyObject> {
+    let options = options_from_kwargs::<CompileOptions>(options)?;
+    if pattern.is_empty() {
+        return Err(PicomatchError::new_err(
+            MatchError::EmptyPattern.to_string(),
+        ));
+    }
+    check_pattern(pattern, &options)?;
+
+    let Some(descriptor) = make_re_impl(pattern, &options, false) else {
+        return Ok(py.None());
+    };
+    map_match_error(check_regex_size(&descriptor.source, &options))?;
+    to_py(py, &descriptor)
+}
+
+#[pymodule]
+#[pyo3(name = "picomatch_rs")]
+pub fn picomatch_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add("PicomatchError", module.py().get_type::<PicomatchError>())?;
+    module.add_class::<Matcher>()?;
+    module.add_function(wrap_pyfunction!(is_match, module)?)?;
+    module.add_function(wrap_pyfunction!(compile_matcher, module)?)?;
+    module.add_function(wrap_pyfunction!(filter, module)?)?;
+    module.add_function(wrap_pyfunction!(scan, module)?)?;
+    module.add_function(wrap_pyfunction!(make_re, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::ffi::CString;
+use std::sync::Once;
+
+use picomatch_rs_python::picomatch_module;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+fn run(script: &str) {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| pyo3::append_to_inittab!(picomatch_module));
+
+    Python::with_gil(|py| {
+        let globals = PyDict::new(py);
+        let code = CString::new(format!("import picomatch_rs as pm\n{script}")).unwrap();
+        if let Err(err) = py.run(&code, Some(&globals), None) {
+            err.print(py);
+            panic!("python script failed: {err}");
+        }
+    });
+}
+
+#[test]
+fn is_match_agrees_with_globstar_and_dotfile_rules() {
+    run(r#"
+assert pm.is_match("data/2024/01/part-0.parquet", "data/**/*.parquet")
+assert not pm.is_match("data/.cache/part-0.parquet", "data/**/*.parquet")
+assert pm.is_match("data/.cache/part-0.parquet", "data/**/*.parquet", dot=True)
+assert pm.is_match("README.MD", ["*.txt", "*.md"], nocase=True)
+assert pm.is_match("a/b/c.csv", "*.csv", match_base=True)
+assert not pm.is_match("a.js", "!(*.js)")
+assert pm.is_match("a.js", "!(*.js)", noext=True) is False
+"#);
+}
+
+#[test]
+fn compiled_matchers_filter_batches() {
+    run(r#"
+matcher = pm.compile_matcher(["**/*.csv", "**/*.parquet"])
+assert matcher.patterns == ["**/*.csv", "**/*.parquet"]
+assert matcher("x/y.csv") and matcher.is_match("y.parquet")
+manifest = ["a.csv", "b/c.parquet", "b/.d.csv", "e.json"]
+assert matcher.filter(manifest) == ["a.csv", "b/c.parquet"]
+assert pm.filter(manifest, "**/*.csv", dot=True) == ["a.csv", "b/.d.csv"]
+assert repr(matcher) == 'Matcher(["**/*.csv", "**/*.parquet"])'
+"#);
+}
+
+#[test]
+fn scan_and_make_re_return_dicts() {
+    run(r#"
+state = pm.scan("data/**/*.parquet", parts=True)
+assert state["base"] == "data" and state["glob"] == "**/*.parquet"
+assert state["is_globstar"] is True
+assert state["parts"] == ["data", "**", "*.parquet"]
+
+regex = pm.make_re("*.js")
+assert regex["source"] == r"^(?:(?!\.)(?=.)[^/]*?\.js)$"
+assert regex["flags"] == ""
+assert pm.make_re("*.js", nocase=True)["flags"] == "i"
+"#);
+}
+
+#[test]
+fn errors_raise_python_exceptions() {
+    run(r#"
+def raises(kind, fn, *args, **kwargs):
+    try:
+        fn(*args, **kwargs)
+    except kind as err:
+        return str(err)
+    raise AssertionError(f"{fn.__name__} did not raise {kind.__name__}")
+
+assert issubclass(pm.PicomatchError, ValueError)
+assert "non-empty" in raises(pm.PicomatchError, pm.compile_matcher, "")
+assert "regex group" in raises(pm.PicomatchError, pm.is_match, "a", "(a|b)", strict_glob=True)
+assert "exceeds maximum" in raises(pm.PicomatchError, pm.make_re, "a" * 10, max_length=3)
+raises(TypeError, pm.is_match, "a", "*", dot="yes")
+raises(TypeError, pm.compile_matcher, 42)
+"#);
+}
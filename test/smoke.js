@@ -15,12 +15,26 @@ describe('package smoke', () => {
     assert.equal(typeof native.isMatch, 'function');
     assert.equal(typeof native.compileMatcher, 'function');
     assert.equal(typeof native.NativeMatcher, 'function');
+    assert.equal(typeof native.isGlob, 'function');
+    assert.equal(typeof native.isDynamicPattern, 'function');
+    assert.equal(typeof native.globParent, 'function');
   });
 
   it('supports representative matching flows', () => {
     const scanState = native.scan('src/**/*.rs');
     assert.equal(scanState.isGlob, true);
 
+    const { tokens } = native.scan('src/**/*.rs', { tokens: true });
+    assert.deepEqual(
+      tokens.map(token => [token.value, token.start, token.end]),
+      [['src', 0, 3], ['**', 4, 6], ['*.rs', 7, 11]]
+    );
+    const parsed = native.parse('a*.txt');
+    assert.deepEqual(
+      parsed.tokens.map(token => [token.type, token.start, token.end]),
+      [['bos', 0, 0], ['text', 0, 1], ['star', 1, 2], ['text', 2, 6]]
+    );
+
     const descriptor = native.makeRe('**/*.rs', null, false, true);
     assert.equal(typeof descriptor.source, 'string');
     assert.equal(descriptor.state.input, '**/*.rs');
@@ -32,4 +46,14 @@ describe('package smoke', () => {
     assert.equal(matcher.test('src/lib.rs'), true);
     assert.equal(matcher.test('src/lib.ts'), false);
   });
+
+  it('exposes the is-glob and glob-parent helpers', () => {
+    assert.equal(native.isGlob('src/*.rs'), true);
+    assert.equal(native.isGlob('src/(a).rs'), false);
+    assert.equal(native.isGlob('src/(a).rs', { strict: false }), true);
+    assert.equal(native.isDynamicPattern('src/{a,b}.rs'), true);
+    assert.equal(native.isDynamicPattern('src/{a,b}.rs', { nobrace: true }), false);
+    assert.equal(native.globParent('src/**/*.rs'), 'src');
+    assert.equal(native.globParent('src/\\[a]/'), 'src/[a]');
+  });
 });
@@ -0,0 +1,350 @@
+use js_sys::RegExp;
+use picomatch_rs::{
+    check_pattern_limits, check_regex_size, check_strict_glob, compile_matcher,
+    make_re as make_re_impl, parse as parse_impl, CompileOptions, MatchError, ParseState,
+    RegexDescriptor, ScanOptions,
+};
+use serde::Serialize;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+// Errors stay plain messages until they cross into JS, so everything below
+// the exported functions also runs in host tests.
+type Result<T> = std::result::Result<T, String>;
+type JsResult<T> = std::result::Result<T, JsError>;
+
+const DEFAULT_MAX_LENGTH: usize = 65536;
+
+fn js_error(message: String) -> JsError {
+    JsError::new(&message)
+}
+
+fn value_from_js(value: JsValue) -> JsResult<Option<Value>> {
+    if value.is_undefined() || value.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_wasm_bindgen::from_value(value)?))
+}
+
+fn to_js<T: Serialize + ?Sized>(value: &T) -> JsResult<JsValue> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+fn normalize_compile_options_value(mut value: Option<Value>) -> Option<Value> {
+    if let Some(Value::Object(object)) = value.as_mut() {
+        if !object.contains_key("noextglob") {
+            if let Some(Value::Bool(enabled)) = object.get("noext") {
+                object.insert("noextglob".to_string(), Value::Bool(*enabled));
+            }
+        }
+    }
+
+    value
+}
+
+fn compile_options_from_value(value: Option<Value>) -> Result<CompileOptions> {
+    Ok(normalize_compile_options_value(value)
+        .map(serde_json::from_value::<CompileOptions>)
+        .transpose()
+        .map_err(|err| err.to_string())?
+        .unwrap_or_default())
+}
+
+fn scan_options_from_value(value: Option<Value>) -> Result<ScanOptions> {
+    Ok(value
+        .map(serde_json::from_value::<ScanOptions>)
+        .transpose()
+        .map_err(|err| err.to_string())?
+        .unwrap_or_default())
+}
+
+fn check_max_length(input: &str, max: Option<usize>) -> Result<()> {
+    let limit = max.unwrap_or(DEFAULT_MAX_LENGTH);
+    let len = input.len();
+    if len > limit {
+        Err(format!(
+            "Input length: {len}, exceeds maximum allowed length: {limit}"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn map_match_error<T>(result: std::result::Result<T, MatchError>) -> Result<T> {
+    result.map_err(|err| err.to_string())
+}
+
+fn parse_patterns(value: Option<Value>) -> Result<Vec<String>> {
+    let patterns = match value {
+        Some(Value::String(pattern)) => vec![pattern],
+        Some(Value::Array(values)) => values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(pattern) => Ok(pattern),
+                _ => Err("Expected pattern to be a string or an array of strings".to_string()),
+            })
+            .collect::<Result<_>>()?,
+        _ => return Err("Expected pattern to be a non-empty string".to_string()),
+    };
+
+    for pattern in &patterns {
+        ensure_non_empty_pattern(pattern)?;
+    }
+
+    Ok(patterns)
+}
+
+fn ensure_non_empty_pattern(pattern: &str) -> Result<()> {
+    if pattern.is_empty() {
+        return Err("Expected pattern to be a non-empty string".to_string());
+    }
+
+    Ok(())
+}
+
+fn check_pattern(pattern: &str, options: &CompileOptions) -> Result<()> {
+    check_max_length(pattern, options.max_length)?;
+    map_match_error(check_pattern_limits(pattern, options))?;
+    map_match_error(check_strict_glob(pattern, options))
+}
+
+fn make_re_descriptor(
+    input: &str,
+    options: Option<Value>,
+    return_state: bool,
+) -> Result<Option<RegexDescriptor>> {
+    let options = compile_options_from_value(options)?;
+    ensure_non_empty_pattern(input)?;
+    check_pattern(input, &options)?;
+
+    let Some(descriptor) = make_re_impl(input, &options, return_state) else {
+        return Ok(None);
+    };
+    map_match_error(check_regex_size(&descriptor.source, &options))?;
+    Ok(Some(descriptor))
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Parsed {
+    One(Option<ParseState>),
+    Many(Vec<Option<ParseState>>),
+}
+
+fn parse_value(input: Option<Value>, options: Option<Value>) -> Result<Parsed> {
+    let options = compile_options_from_value(options)?;
+
+    match input {
+        Some(Value::String(pattern)) => Ok(Parsed::One(parse_impl(&pattern, &options))),
+        Some(Value::Array(patterns)) => patterns
+            .into_iter()
+            .map(|pattern| match pattern {
+                Value::String(pattern) => Ok(parse_impl(&pattern, &options)),
+                _ => Err("Expected pattern to be a string or an array of strings".to_string()),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Parsed::Many),
+        _ => Err("Expected pattern to be a string or an array of strings".to_string()),
+    }
+}
+
+#[wasm_bindgen]
+pub struct Matcher {
+    patterns: Vec<String>,
+    matchers: Vec<picomatch_rs::Matcher>,
+}
+
+impl Matcher {
+    fn from_values(patterns: Option<Value>, options: Option<Value>) -> Result<Matcher> {
+        let options = compile_options_from_value(options)?;
+        let patterns = parse_patterns(patterns)?;
+        let mut matchers = Vec::with_capacity(patterns.len());
+
+        for pattern in &patterns {
+            check_pattern(pattern, &options)?;
+            matchers.push(map_match_error(compile_matcher(pattern, &options))?);
+        }
+
+        Ok(Matcher { patterns, matchers })
+    }
+
+    fn matches(&self, input: &str) -> Result<bool> {
+        for matcher in &self.matchers {
+            if map_match_error(matcher.is_match(input))? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+#[wasm_bindgen]
+impl Matcher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(patterns: JsValue, options: JsValue) -> JsResult<Matcher> {
+        Matcher::from_values(value_from_js(patterns)?, value_from_js(options)?).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = "isMatch")]
+    pub fn is_match(&self, input: &str) -> JsResult<bool> {
+        self.matches(input).map_err(js_error)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn patterns(&self) -> Vec<String> {
+        self.patterns.clone()
+    }
+}
+
+#[wasm_bindgen(js_name = "isMatch")]
+pub fn is_match(input: &str, patterns: JsValue, options: JsValue) -> JsResult<bool> {
+    Matcher::new(patterns, options)?.is_match(input)
+}
+
+// Like the Node addon, returns a `RegExp`, or the output string or the
+// descriptor with its parse state when those are asked for.
+#[wasm_bindgen(js_name = "makeRe")]
+pub fn make_re(
+    input: &str,
+    options: JsValue,
+    return_output: Option<bool>,
+    return_state: Option<bool>,
+) -> JsResult<JsValue> {
+    let return_state = return_state.unwrap_or(false);
+    let descriptor =
+        make_re_descriptor(input, value_from_js(options)?, return_state).map_err(js_error)?;
+    let Some(descriptor) = descriptor else {
+        return Ok(JsValue::NULL);
+    };
+
+    if return_output.unwrap_or(false) {
+        return Ok(JsValue::from_str(&descriptor.output));
+    }
+    if return_state {
+        return to_js(&descriptor);
+    }
+
+    Ok(RegExp::new(&descriptor.source, &descriptor.flags).into())
+}
+
+#[wasm_bindgen]
+pub fn scan(input: &str, options: JsValue) -> JsResult<JsValue> {
+    let options = scan_options_from_value(value_from_js(options)?).map_err(js_error)?;
+    to_js(&picomatch_rs::scan(input, &options))
+}
+
+#[wasm_bindgen]
+pub fn parse(input: JsValue, options: JsValue) -> JsResult<JsValue> {
+    to_js(&parse_value(value_from_js(input)?, value_from_js(options)?).map_err(js_error)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        compile_options_from_value, make_re_descriptor, parse_value, scan_options_from_value,
+        Matcher, Parsed,
+    };
+
+    fn compile(patterns: serde_json::Value, options: serde_json::Value) -> Matcher {
+        Matcher::from_values(Some(patterns), Some(options)).unwrap()
+    }
+
+    fn error(patterns: serde_json::Value, options: serde_json::Value) -> String {
+        match Matcher::from_values(Some(patterns), Some(options)) {
+            Ok(_) => panic!("expected an error"),
+            Err(message) => message,
+        }
+    }
+
+    #[test]
+    fn parses_options_objects() {
+        let options = compile_options_from_value(Some(json!({
+            "dot": true,
+            "matchBase": true,
+            "noext": true,
+            "maxLength": 10,
+        })))
+        .unwrap();
+        assert!(options.dot && options.match_base && options.noextglob);
+        assert_eq!(options.max_length, Some(10));
+
+        let options = compile_options_from_value(Some(json!({
+            "noext": true,
+            "noextglob": false,
+        })))
+        .unwrap();
+        assert!(!options.noextglob);
+
+        assert!(!compile_options_from_value(None).unwrap().dot);
+        assert!(
+            scan_options_from_value(Some(json!({ "parts": true })))
+                .unwrap()
+                .parts
+        );
+        assert!(compile_options_from_value(Some(json!({ "dot": "yes" })))
+            .unwrap_err()
+            .contains("invalid type"));
+    }
+
+    #[test]
+    fn matches_any_pattern() {
+        let matcher = compile(json!(["*.md", "src/**/*.rs"]), json!({}));
+        assert_eq!(matcher.patterns(), ["*.md", "src/**/*.rs"]);
+        assert!(matcher.matches("src/a/lib.rs").unwrap());
+        assert!(matcher.matches("README.md").unwrap());
+        assert!(!matcher.matches("src/.hidden.rs").unwrap());
+
+        let matcher = compile(json!("src/**/*.rs"), json!({ "dot": true }));
+        assert!(matcher.matches("src/.hidden.rs").unwrap());
+        assert!(compile(json!("@(a|b)"), json!({})).matches("a").unwrap());
+        assert!(!compile(json!("@(a|b)"), json!({ "noext": true }))
+            .matches("a")
+            .unwrap());
+    }
+
+    #[test]
+    fn maps_errors_to_messages() {
+        assert_eq!(
+            error(json!(""), json!({})),
+            "Expected pattern to be a non-empty string"
+        );
+        assert_eq!(
+            error(json!(["*.js", 1]), json!({})),
+            "Expected pattern to be a string or an array of strings"
+        );
+        assert_eq!(
+            error(json!("*.js"), json!({ "maxLength": 2 })),
+            "Input length: 4, exceeds maximum allowed length: 2"
+        );
+        assert!(Matcher::from_values(None, None).is_err());
+        assert!(error(json!("a(?=b)"), json!({ "strictGlob": true }))
+            .starts_with("disallowed syntax: "));
+        assert!(parse_value(Some(json!(1)), None).is_err());
+    }
+
+    #[test]
+    fn builds_regex_descriptors() {
+        let descriptor = make_re_descriptor("*.js", None, false).unwrap().unwrap();
+        assert!(descriptor.source.starts_with('^'));
+        assert!(descriptor.state.is_none());
+
+        let descriptor = make_re_descriptor("*.js", Some(json!({ "nocase": true })), true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(descriptor.flags, "i");
+        assert!(descriptor.state.is_some());
+
+        assert_eq!(
+            make_re_descriptor("", None, false).unwrap_err(),
+            "Expected pattern to be a non-empty string"
+        );
+        assert!(matches!(
+            parse_value(Some(json!(["*.js", "a/*"])), None),
+            Ok(Parsed::Many(states)) if states.len() == 2
+        ));
+    }
+}